@@ -0,0 +1,43 @@
+//! Lookup benchmarks on a large library.
+//!
+//! Run with `cargo bench --features test-util`.
+
+use std::hint::black_box;
+
+use bevy::prelude::*;
+use criterion::{Criterion, criterion_group, criterion_main};
+use msg_load_folder::prelude::*;
+
+#[derive(Asset, Clone, Reflect)]
+struct BenchAsset;
+
+const COUNT: usize = 10_000;
+
+fn library() -> AssetFolder<String, BenchAsset> {
+    let mut library = AssetFolder::with_capacity(COUNT);
+    library.populate_dummy(COUNT);
+    library
+}
+
+fn lookups(c: &mut Criterion) {
+    let library = library();
+    let ids: Vec<String> = (0..COUNT).map(|index| format!("dummy_{index}")).collect();
+
+    c.bench_function("get_hit", |b| {
+        b.iter(|| {
+            for id in &ids {
+                black_box(library.get(id.clone()));
+            }
+        });
+    });
+    c.bench_function("get_miss", |b| {
+        let missing = "missing".to_string();
+        b.iter(|| black_box(library.get(missing.clone())));
+    });
+    c.bench_function("iter", |b| {
+        b.iter(|| black_box(library.iter().count()));
+    });
+}
+
+criterion_group!(benches, lookups);
+criterion_main!(benches);
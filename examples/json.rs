@@ -0,0 +1,73 @@
+//! Folder-based asset loading from JSON files with msg_load_folder.
+//!
+//! Unlike the RON example, no deserializer plugin is added by hand:
+//! `FolderLoaderPlugin::json` registers the JSON loader for the extension.
+//!
+//! Runs headless (no window) and exits after loading completes.
+//!
+//! Run with: `cargo run --example json --features json`
+
+use bevy::{log::LogPlugin, prelude::*};
+use msg_load_folder::prelude::ids::InternedStrId;
+use msg_load_folder::prelude::*;
+use serde::Deserialize;
+
+/// A spell asset loaded from JSON files.
+#[derive(Asset, Clone, Reflect, Deserialize, Debug)]
+pub struct Spell {
+    pub name: String,
+    pub damage: f32,
+    pub mana_cost: u32,
+    #[serde(default)]
+    pub description: String,
+}
+
+/// A unique identifier for spells, e.g. `fireball.spell.json` becomes
+/// `SpellId("fireball")`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct SpellId(InternedStrId);
+
+impl FromFilename for SpellId {
+    fn from_filename(id: &str) -> Self {
+        SpellId(InternedStrId::new(id))
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins(MinimalPlugins)
+        .add_plugins(LogPlugin::default())
+        .add_plugins(AssetPlugin {
+            file_path: "assets".to_string(),
+            ..default()
+        })
+        // Also registers the JSON loader for .spell.json files
+        .add_plugins(FolderLoaderPlugin::<SpellId, Spell>::json(
+            "spells_json",
+            ".spell.json",
+        ))
+        .add_systems(Update, display_spells)
+        .run();
+}
+
+/// Displays the loaded spells once loading is complete, then exits.
+fn display_spells(
+    folder_handle: Res<AssetFolderHandle<Spell>>,
+    spell_library: Res<AssetFolder<SpellId, Spell>>,
+    spell_assets: Res<Assets<Spell>>,
+    mut app_exit: MessageWriter<AppExit>,
+) {
+    if !folder_handle.is_loaded() {
+        return;
+    }
+
+    info!("=== Loaded {} spells ===", spell_library.len());
+    for (id, _, spell) in spell_library.iter_loaded(&spell_assets) {
+        info!(
+            "{:?}: {} ({} damage, {} mana)",
+            id, spell.name, spell.damage, spell.mana_cost
+        );
+    }
+
+    app_exit.write(AppExit::Success);
+}
@@ -12,6 +12,7 @@
 
 use bevy::{log::LogPlugin, prelude::*};
 use bevy_common_assets::ron::RonAssetPlugin;
+use msg_load_folder::prelude::ids::InternedStrId;
 use msg_load_folder::prelude::*;
 use serde::Deserialize;
 
@@ -35,13 +36,14 @@ pub struct Spell {
 
 /// A unique identifier for spells, derived from filenames.
 ///
-/// For example, `fireball.spell.ron` becomes `SpellId("fireball")`.
+/// For example, `fireball.spell.ron` becomes `SpellId("fireball")`. Wrapping
+/// [`InternedStrId`] interns each name once, so reloads don't allocate again.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
-pub struct SpellId(&'static str);
+pub struct SpellId(InternedStrId);
 
-impl From<String> for SpellId {
-    fn from(s: String) -> Self {
-        SpellId(Box::leak(s.into_boxed_str()))
+impl FromFilename for SpellId {
+    fn from_filename(id: &str) -> Self {
+        SpellId(InternedStrId::new(id))
     }
 }
 
@@ -131,10 +133,10 @@ fn display_spells(
 
     // Example: Access a specific spell by ID
     for (id, handle) in spell_library.iter() {
-        if let Some(spell) = spell_assets.get(handle) {
-            if spell.name == "Fireball" {
-                info!("Found Fireball spell with ID: {}", id);
-            }
+        if let Some(spell) = spell_assets.get(handle)
+            && spell.name == "Fireball"
+        {
+            info!("Found Fireball spell with ID: {}", id);
         }
     }
 
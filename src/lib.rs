@@ -9,6 +9,7 @@
 //!
 //! ```rust
 //! use msg_load_folder::prelude::*;
+//! use msg_load_folder::prelude::ids::InternedStrId;
 //! use bevy::prelude::*;
 //! use serde::Deserialize;
 //!
@@ -19,14 +20,15 @@
 //!     damage: f32,
 //! }
 //!
-//! // 2. Define your ID type (implement required traits)
+//! // 2. Define your ID type (must implement FromFilename, which any
+//! //    From<String> type does); InternedStrId interns each name once
+//! //    and can also be used directly
 //! #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
-//! struct SpellId(u64);
+//! struct SpellId(InternedStrId);
 //!
-//! impl From<String> for SpellId {
-//!     fn from(s: String) -> Self {
-//!         // In practice, use interned strings for efficiency
-//!         SpellId(s.len() as u64)
+//! impl FromFilename for SpellId {
+//!     fn from_filename(id: &str) -> Self {
+//!         SpellId(InternedStrId::new(id))
 //!     }
 //! }
 //!
@@ -51,19 +53,54 @@
 //! }
 //! ```
 
-use std::collections::HashMap;
+use std::any::{Any, TypeId, type_name};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 use std::marker::PhantomData;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime};
 
-use bevy::asset::LoadedFolder;
+use bevy::asset::io::{AssetReaderError, AssetSourceId, ErasedAssetReader};
+use bevy::asset::{
+    AssetLoadError, LoadState, LoadedFolder, LoadedUntypedAsset, RecursiveDependencyLoadState,
+};
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
 use bevy::prelude::*;
+use bevy::reflect::{GetTypeRegistration, Typed};
+use bevy::tasks::futures::check_ready;
+use bevy::tasks::futures_lite::StreamExt;
+use bevy::tasks::{AsyncComputeTaskPool, IoTaskPool, Task};
 
 pub mod prelude {
     pub use crate::{
-        AssetFolder, AssetFolderHandle, AtlasIcon, FolderLoaderPlugin, deserialize_optional_string,
-        id_from_filename, is_hidden_file,
+        AssetFolder, AssetFolderHandle, AssetRef, AssetRegistered, DuplicatePolicy, EntryStatus,
+        FolderDiff, FolderDiscovered, FolderLoadCompleted, FolderLoadError, FolderLoadPriorities,
+        FolderLoadProgress, FolderLoaderPlugin, FolderLoaderPluginGroup, FromFilename, Library,
+        LoadReport, PendingBytes, Phase, SkipReason, SpawnFromFolder, UnloadFolder,
+        deserialize_nonempty_vec, deserialize_optional_f32, deserialize_optional_string,
+        folder_became_ready, id_from_filename, is_hidden_file, resolve_asset_path, same_asset,
+        stable_id_hash,
     };
+
+    /// Ready-made ID types.
+    pub mod ids {
+        pub use crate::InternedStrId;
+    }
+
+    #[cfg(feature = "regex")]
+    pub use crate::id_from_filename_with_regex;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub use crate::{diff_folders, load_folder_blocking};
+
+    #[cfg(feature = "ui")]
+    pub use crate::{AtlasIcon, AtlasIconLibrary};
+
+    #[cfg(feature = "atlas")]
+    pub use crate::{AtlasIconIndex, AtlasIconPlugin};
 }
 
 // =============================================================================
@@ -79,9 +116,14 @@ pub mod prelude {
 ///
 /// # Type Parameters
 ///
-/// * `Id` - The ID type (must implement required traits including `From<String>`)
+/// * `Id` - The ID type (must implement required traits including [`FromFilename`],
+///   which any `From<String>` type does)
 /// * `A` - The asset type (must implement `Asset + Clone`)
 ///
+/// The plugin only maps files to IDs; registering an `AssetLoader` for the
+/// file format stays up to the app, e.g. with `bevy_common_assets`. With the
+/// `json` feature, [`Self::json`] registers a JSON loader as well.
+///
 /// # Example
 ///
 /// ```rust
@@ -108,17 +150,63 @@ pub mod prelude {
 /// ```
 pub struct FolderLoaderPlugin<Id, A>
 where
-    Id: Clone + Copy + Eq + Hash + Send + Sync + Default + From<String> + 'static,
+    Id: Clone + Eq + Hash + Send + Sync + Default + FromFilename + 'static,
     A: Asset + Clone + Send + Sync + 'static,
 {
     folder_path: &'static str,
-    file_extension: &'static str,
+    extra_folders: Vec<&'static str>,
+    file_extensions: Vec<&'static str>,
+    priority: i32,
+    on_progress: Option<fn(usize, usize)>,
+    limit: Option<usize>,
+    concurrency_hint: Option<usize>,
+    #[cfg(feature = "regex")]
+    id_regex: Option<regex::Regex>,
+    #[cfg(feature = "manifest")]
+    manifest: Option<&'static str>,
+    ready_at_count: usize,
+    locales: Option<(&'static str, &'static str)>,
+    on_failure: Option<fn(Id, &AssetServer) -> Option<Handle<A>>>,
+    fallback_path: Option<&'static str>,
+    reload_only_if_modified: bool,
+    dependency_fn: Option<fn(&A) -> Vec<String>>,
+    wait_for_dependencies: bool,
+    staged_reloads: bool,
+    compact_on_load: bool,
+    hot_reload: bool,
+    retain_folder_handle: bool,
+    pending_bytes: Option<PendingBytes>,
+    allow_empty_id: bool,
+    two_pass: bool,
+    async_id_extraction: bool,
+    on_skip: Option<fn(&Path, SkipReason)>,
+    numeric_keys: bool,
+    folder_defaults: Option<(&'static str, fn(&mut A, &A))>,
+    validate_id: Option<fn(&str) -> bool>,
+    include_patterns: Vec<String>,
+    exclude_patterns: Vec<String>,
+    skip_prefixes: &'static [char],
+    id_parser: Option<IdParser<Id>>,
+    validator: Option<AssetValidator<A>>,
+    subtype_delimiter: Option<char>,
+    recursive: bool,
+    max_depth: Option<usize>,
+    id_base_strip: usize,
+    case_insensitive: bool,
+    duplicate_policy: DuplicatePolicy,
+    sort_key: Option<fn(&A) -> i64>,
+    preview: Option<(&'static str, AddPreview)>,
+    register_reflection: Option<fn(&mut App)>,
+    register_loader: Option<fn(&mut App, &[&'static str])>,
+    schedule: InternedScheduleLabel,
+    #[cfg(feature = "state")]
+    state_gate: Option<Box<dyn Fn(&mut App, InternedScheduleLabel, LoaderSystems) + Send + Sync>>,
     _marker: PhantomData<(Id, A)>,
 }
 
 impl<Id, A> FolderLoaderPlugin<Id, A>
 where
-    Id: Clone + Copy + Eq + Hash + Send + Sync + Default + From<String> + 'static,
+    Id: Clone + Eq + Hash + Send + Sync + Default + FromFilename + 'static,
     A: Asset + Clone + Send + Sync + 'static,
 {
     /// Creates a new folder loader plugin.
@@ -131,768 +219,9841 @@ where
     ///   (e.g., ".spell.ron")
     #[must_use]
     pub fn new(folder_path: &'static str, file_extension: &'static str) -> Self {
+        Self::with_extensions(folder_path, &[file_extension])
+    }
+
+    /// Creates a folder loader matching any of several file extensions.
+    ///
+    /// Useful while migrating formats, e.g. `&[".spell.ron", ".spell.json"]`
+    /// loads both variants into the same library. Extensions are tried in
+    /// order and the first one a filename ends with is stripped for the ID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `file_extensions` is empty.
+    #[must_use]
+    pub fn with_extensions(folder_path: &'static str, file_extensions: &[&'static str]) -> Self {
+        assert!(
+            !file_extensions.is_empty(),
+            "FolderLoaderPlugin for '{folder_path}' needs at least one file extension"
+        );
         Self {
             folder_path,
-            file_extension,
+            extra_folders: Vec::new(),
+            file_extensions: file_extensions.to_vec(),
+            priority: 0,
+            on_progress: None,
+            limit: None,
+            concurrency_hint: None,
+            #[cfg(feature = "regex")]
+            id_regex: None,
+            #[cfg(feature = "manifest")]
+            manifest: None,
+            ready_at_count: 1,
+            locales: None,
+            on_failure: None,
+            fallback_path: None,
+            reload_only_if_modified: false,
+            dependency_fn: None,
+            wait_for_dependencies: false,
+            staged_reloads: false,
+            compact_on_load: false,
+            hot_reload: false,
+            retain_folder_handle: true,
+            pending_bytes: None,
+            allow_empty_id: false,
+            two_pass: false,
+            async_id_extraction: false,
+            on_skip: None,
+            numeric_keys: false,
+            folder_defaults: None,
+            validate_id: None,
+            include_patterns: Vec::new(),
+            exclude_patterns: Vec::new(),
+            skip_prefixes: DEFAULT_SKIP_PREFIXES,
+            id_parser: None,
+            validator: None,
+            subtype_delimiter: None,
+            recursive: false,
+            max_depth: None,
+            id_base_strip: 0,
+            case_insensitive: false,
+            duplicate_policy: DuplicatePolicy::Warn,
+            sort_key: None,
+            preview: None,
+            #[cfg(feature = "state")]
+            state_gate: None,
+            schedule: Update.intern(),
+            register_reflection: None,
+            register_loader: None,
             _marker: PhantomData,
         }
     }
-}
-
-impl<Id, A> Plugin for FolderLoaderPlugin<Id, A>
-where
-    Id: Clone + Copy + Eq + Hash + Send + Sync + Default + From<String> + std::fmt::Debug + 'static,
-    A: Asset + Clone + Send + Sync + 'static,
-{
-    fn build(&self, app: &mut App) {
-        // Store config in a resource
-        app.insert_resource(FolderLoaderConfig::<Id, A> {
-            folder_path: self.folder_path,
-            file_extension: self.file_extension,
-            _marker: PhantomData,
-        });
-
-        // Initialize resources
-        app.init_asset::<A>();
-        app.init_resource::<AssetFolderHandle<A>>();
-        app.init_resource::<AssetFolder<Id, A>>();
 
-        // Add the loading system
-        app.add_systems(Update, load_assets_from_folder::<Id, A>);
+    /// Creates a folder loader that merges several folders into one library.
+    ///
+    /// Useful for a base game and DLC or patch folders, e.g.
+    /// `&["prefabs/spells", "dlc/spells"]`. Each folder is scanned like a
+    /// single one and files are considered in folder order, so with the
+    /// default [`DuplicatePolicy::Warn`] (or `KeepLast`) a later folder
+    /// overrides an earlier one for the same ID; use
+    /// [`DuplicatePolicy::KeepFirst`] to keep the earliest. The folder load
+    /// completes once every folder has been processed.
+    ///
+    /// If another loader for the same asset type already loads one of the
+    /// folders, this whole plugin is skipped with a warning, including its
+    /// other folders.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `folder_paths` is empty.
+    #[must_use]
+    pub fn with_folders(folder_paths: &[&'static str], file_extension: &'static str) -> Self {
+        let (&folder_path, extra_folders) = folder_paths
+            .split_first()
+            .expect("FolderLoaderPlugin::with_folders needs at least one folder");
+        Self {
+            extra_folders: extra_folders.to_vec(),
+            ..Self::new(folder_path, file_extension)
+        }
     }
-}
-
-/// Configuration resource for folder loading.
-#[derive(Resource)]
-struct FolderLoaderConfig<Id, A>
-where
-    Id: Clone + Copy + Eq + Hash + Send + Sync + Default + From<String> + 'static,
-    A: Asset + Clone + Send + Sync + 'static,
-{
-    folder_path: &'static str,
-    file_extension: &'static str,
-    _marker: PhantomData<(Id, A)>,
-}
-
-// =============================================================================
-// AssetFolderHandle Resource
-// =============================================================================
-
-/// Resource tracking folder load state for an asset type.
-///
-/// Generic over a marker type `A` to allow multiple folder handles
-/// for different asset types (spells, perks, actors, etc.).
-#[derive(Resource, Reflect)]
-#[reflect(Resource)]
-pub struct AssetFolderHandle<A: Send + Sync + 'static> {
-    /// Handle to the loaded folder.
-    pub handle: Option<Handle<LoadedFolder>>,
-    /// Whether the folder has been processed.
-    processed: bool,
-    #[reflect(ignore)]
-    _marker: PhantomData<A>,
-}
 
-impl<A: Send + Sync + 'static> Default for AssetFolderHandle<A> {
-    fn default() -> Self {
-        Self::new()
+    /// Creates a folder loader that keeps subfolders apart in the IDs.
+    ///
+    /// A file in a subfolder gets its relative path as ID, so
+    /// `spells/fire/fireball.spell.ron` becomes `fire/fireball` and no longer
+    /// collides with `spells/ice/fireball.spell.ron`. Files directly in the
+    /// folder keep their plain stem. Files nested more than `max_depth`
+    /// subfolders deep are skipped; `None` allows any depth.
+    #[must_use]
+    pub fn new_recursive(
+        folder_path: &'static str,
+        file_extension: &'static str,
+        max_depth: Option<usize>,
+    ) -> Self {
+        Self {
+            recursive: true,
+            max_depth,
+            ..Self::new(folder_path, file_extension)
+        }
     }
-}
 
-impl<A: Send + Sync + 'static> AssetFolderHandle<A> {
-    /// Create a new folder handle.
+    /// Creates a folder loader for JSON files that also registers their loader.
+    ///
+    /// Adds `bevy_common_assets`' `JsonAssetPlugin<A>` for `file_extension`,
+    /// so the asset type doesn't need its deserializer plugin wired up
+    /// separately. The loader is only added once per asset type, with the
+    /// extensions of the first loader; later loaders for other extensions
+    /// log a warning. Requires the `json` feature.
+    #[cfg(feature = "json")]
     #[must_use]
-    pub fn new() -> Self {
+    pub fn json(folder_path: &'static str, file_extension: &'static str) -> Self
+    where
+        A: for<'de> serde::Deserialize<'de>,
+    {
         Self {
-            handle: None,
-            processed: false,
-            _marker: PhantomData,
+            register_loader: Some(register_json_loader::<A>),
+            ..Self::new(folder_path, file_extension)
         }
     }
 
-    /// Check if the folder has been processed.
+    /// Runs the loading systems only while `state` is active.
+    ///
+    /// With `next_state`, the loader switches to it once the folder has been
+    /// processed, which covers a simple `AssetLoading -> InGame` flow. With
+    /// several folders gating the same state, leave `next_state` unset and
+    /// transition once all of them are loaded. The state check applies to
+    /// the schedule set with [`Self::in_schedule`], so the loader stays idle
+    /// outside `state` in whichever schedule it runs. Requires the `state`
+    /// feature and Bevy's `StatesPlugin`.
+    #[cfg(feature = "state")]
     #[must_use]
-    pub fn is_loaded(&self) -> bool {
-        self.processed
+    pub fn run_in_state<S>(mut self, state: S, next_state: Option<S>) -> Self
+    where
+        Id: std::fmt::Debug,
+        S: bevy::state::state::FreelyMutableState,
+    {
+        self.state_gate = Some(Box::new(move |app, schedule, systems| {
+            app.add_systems(schedule, systems.run_if(in_state(state.clone())));
+            if let Some(next_state) = next_state.clone() {
+                app.add_systems(
+                    schedule,
+                    (move |folder_handle: Res<AssetFolderHandle<A>>,
+                           mut next: ResMut<NextState<S>>| {
+                        if folder_handle.is_loaded() {
+                            next.set(next_state.clone());
+                        }
+                    })
+                    .after(load_assets_from_folder::<Id, A>)
+                    .run_if(in_state(state.clone())),
+                );
+            }
+        }));
+        self
     }
-}
 
-// =============================================================================
-// AssetFolder Resource
-// =============================================================================
+    /// Sets the load priority of this folder (default `0`).
+    ///
+    /// Folders with a higher priority start loading first: this folder
+    /// defers its `load_folder` call until every registered folder with a
+    /// strictly higher priority has finished processing. Folders sharing a
+    /// priority load concurrently. See [`FolderLoadPriorities`].
+    #[must_use]
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
 
-/// Generic library resource for assets loaded from folders.
-///
-/// Maps asset IDs to their handles, providing convenient access methods.
-/// This is the main resource created by `FolderLoaderPlugin`.
-///
-/// # Type Parameters
-///
-/// * `Id` - The ID type (e.g., SpellId, PerkId)
-/// * `A` - The asset type (e.g., Spell, PerkData)
-///
-/// # Example
-///
-/// ```rust
-/// # use msg_load_folder::prelude::*;
-/// # use bevy::prelude::*;
-/// # use serde::Deserialize;
-/// # #[derive(Asset, Clone, Reflect, Deserialize)]
-/// # struct Spell { name: String }
-/// # #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
-/// # struct SpellId(u64);
-/// # impl From<String> for SpellId { fn from(s: String) -> Self { SpellId(s.len() as u64) } }
-/// fn my_system(
-///     library: Res<AssetFolder<SpellId, Spell>>,
-///     assets: Res<Assets<Spell>>,
-/// ) {
-///     let spell_id = SpellId::default();
-///     if let Some(handle) = library.get(spell_id) {
-///         if let Some(spell) = assets.get(handle) {
-///             info!("Found spell: {}", spell.name);
-///         }
-///     }
-/// }
-/// ```
-#[derive(Resource, Clone, Reflect, Deref, DerefMut)]
-pub struct AssetFolder<Id, A>
-where
-    Id: Clone + Copy + Eq + Hash + Send + Sync + 'static,
-    A: Asset + Clone + Send + Sync + 'static,
-{
-    /// Asset handles indexed by ID.
-    #[reflect(ignore)]
-    assets: HashMap<Id, Handle<A>>,
-}
+    /// Sets a callback invoked with `(loaded, total)` file counts during loading.
+    ///
+    /// The callback runs on frames where the counts change, which makes it a
+    /// lightweight way to drive a custom progress UI without polling resources.
+    ///
+    /// Per-file progress needs [`Self::two_pass`]. Otherwise nothing is known
+    /// until `load_folder` resolves with every file loaded, so the callback
+    /// jumps straight to the final count.
+    #[must_use]
+    pub fn on_progress(mut self, on_progress: fn(usize, usize)) -> Self {
+        self.on_progress = Some(on_progress);
+        self
+    }
 
-// Manual Default implementation that doesn't require A: Default
-impl<Id, A> Default for AssetFolder<Id, A>
-where
-    Id: Clone + Copy + Eq + Hash + Send + Sync + 'static,
-    A: Asset + Clone + Send + Sync + 'static,
-{
-    fn default() -> Self {
-        Self::new()
+    /// Sets a callback invoked for every file the loader skips.
+    ///
+    /// Receives the path and why it was skipped, e.g. for a linting tool
+    /// that suggests fixes for misnamed files.
+    #[must_use]
+    pub fn on_skip(mut self, on_skip: fn(&Path, SkipReason)) -> Self {
+        self.on_skip = Some(on_skip);
+        self
     }
-}
 
-impl<Id, A> AssetFolder<Id, A>
-where
-    Id: Clone + Copy + Eq + Hash + Send + Sync + 'static,
-    A: Asset + Clone + Send + Sync + 'static,
-{
-    /// Create a new empty library.
+    /// Caps the number of files registered from the folder.
+    ///
+    /// Matching files are sorted by path and only the first `limit` are
+    /// registered, so the selection is deterministic. With
+    /// [`Self::two_pass`] the files beyond the limit are never loaded;
+    /// otherwise `load_folder` has loaded them already. This is a development
+    /// convenience for faster startup and is not meant for production builds.
     #[must_use]
-    pub fn new() -> Self {
-        Self {
-            assets: HashMap::new(),
-        }
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
     }
 
-    /// Get handle for an ID.
+    /// Hints how many assets of this folder should load at the same time.
+    ///
+    /// Bevy's asset server has no per-folder concurrency setting:
+    /// `load_folder` starts every file at once, and the number of IO threads
+    /// is global (see `TaskPoolOptions` on `TaskPoolPlugin`). The hint is
+    /// therefore only applied with [`Self::two_pass`], where the loader
+    /// starts at most `max` matching files and starts the next ones as
+    /// earlier files finish.
     #[must_use]
-    pub fn get(&self, id: Id) -> Option<&Handle<A>> {
-        self.assets.get(&id)
+    pub fn concurrency_hint(mut self, max: usize) -> Self {
+        self.concurrency_hint = Some(max.max(1));
+        self
     }
 
-    /// Get mutable handle for an ID.
+    /// Requires at least `count` assets before the library reports ready.
+    ///
+    /// Affects [`AssetFolder::is_ready`] and [`folder_became_ready`]. Readiness
+    /// is independent of discovery: if the folder contains fewer than `count`
+    /// matching files the library never becomes ready, so use
+    /// [`AssetFolderHandle::is_loaded`] to detect that loading has finished.
     #[must_use]
-    pub fn get_mut(&mut self, id: Id) -> Option<&mut Handle<A>> {
-        self.assets.get_mut(&id)
+    pub fn ready_at_count(mut self, count: usize) -> Self {
+        self.ready_at_count = count;
+        self
     }
 
-    /// Insert a handle for an ID.
-    pub fn insert(&mut self, id: Id, handle: Handle<A>) -> Option<Handle<A>> {
-        self.assets.insert(id, handle)
+    /// Sets a hook providing a substitute when an asset fails to load.
+    ///
+    /// The hook receives the ID of the failed asset; if it returns a handle
+    /// (e.g. to a generic placeholder) that handle is registered under the ID
+    /// instead of the failure being recorded, keeping the library complete
+    /// even with broken content.
+    #[must_use]
+    pub fn on_failure(mut self, on_failure: fn(Id, &AssetServer) -> Option<Handle<A>>) -> Self {
+        self.on_failure = Some(on_failure);
+        self
     }
 
-    /// Check if the library contains an ID.
+    /// Loads the asset at `path` as the library's fallback.
+    ///
+    /// [`AssetFolder::get_or_default`] returns it for unknown IDs, so content
+    /// referencing a missing ID gets a placeholder instead of a panic. The
+    /// fallback loads on its own as soon as the plugin is built, regardless
+    /// of the folder's priority or state gating; it is not part of the
+    /// library and never counted as loaded.
     #[must_use]
-    pub fn contains(&self, id: Id) -> bool {
-        self.assets.contains_key(&id)
+    pub fn with_fallback(mut self, path: &'static str) -> Self {
+        self.fallback_path = Some(path);
+        self
     }
 
-    /// Check if any assets have been loaded.
+    /// Sets a function listing asset paths referenced by each asset.
+    ///
+    /// When an asset is registered, every returned path (e.g. a sound
+    /// referenced by a spell) is loaded through the `AssetServer` and the
+    /// handle kept alive by the [`AssetFolderHandle`].
     #[must_use]
-    pub fn is_ready(&self) -> bool {
-        !self.assets.is_empty()
+    pub fn with_dependency_fn(mut self, dependency_fn: fn(&A) -> Vec<String>) -> Self {
+        self.dependency_fn = Some(dependency_fn);
+        self
     }
 
-    /// Get all known IDs.
-    pub fn keys(&self) -> impl Iterator<Item = Id> + '_ {
-        self.assets.keys().copied()
+    /// Merges defaults from a folder-level file into each loaded asset.
+    ///
+    /// `file_name` is loaded as an `A` from the folder itself (e.g.
+    /// `_defaults.spell.ron`, which the `_` prefix keeps out of the library)
+    /// before any asset registers. `merge` then receives each asset along
+    /// with the defaults, typically filling fields left unspecified. Assets
+    /// added or hot-reloaded after the folder has been processed are not
+    /// merged.
+    #[must_use]
+    pub fn with_folder_defaults(mut self, file_name: &'static str, merge: fn(&mut A, &A)) -> Self {
+        self.folder_defaults = Some((file_name, merge));
+        self
     }
 
-    /// Returns the number of loaded assets.
+    /// Waits for dependencies before registering assets and marking the
+    /// folder loaded.
+    ///
+    /// Each asset is only registered once its whole dependency tree (e.g.
+    /// a `Handle<Image>` its loader loaded) has finished, so systems never
+    /// see an asset whose sprite is still missing. With
+    /// [`Self::with_dependency_fn`] the folder additionally waits for the
+    /// listed paths. A dependency that fails to load is recorded as a
+    /// failure instead of blocking the folder. Off by default since it
+    /// checks the dependency tree of every pending asset each frame.
     #[must_use]
-    pub fn len(&self) -> usize {
-        self.assets.len()
+    pub fn wait_for_dependencies(mut self, enabled: bool) -> Self {
+        self.wait_for_dependencies = enabled;
+        self
     }
 
-    /// Returns `true` if no assets are loaded.
+    /// Skips requested reloads when nothing in the folder changed.
+    ///
+    /// When enabled, [`AssetFolderHandle::request_reload`] compares the latest
+    /// modification time of the folder and its files against the one seen at
+    /// the last load, and only rescans if it changed. The folder is resolved
+    /// on disk relative to `AssetPlugin::file_path`, so this requires the
+    /// `AssetPlugin` to be added first and only applies to the default file
+    /// source. On WASM, where `std::fs` is unavailable, every reload rescans.
     #[must_use]
-    pub fn is_empty(&self) -> bool {
-        self.assets.is_empty()
+    pub fn reload_only_if_modified(mut self, enabled: bool) -> Self {
+        self.reload_only_if_modified = enabled;
+        self
     }
 
-    /// Returns an iterator over all IDs and their handles.
-    pub fn iter(&self) -> impl Iterator<Item = (Id, &Handle<A>)> + '_ {
-        self.assets.iter().map(|(id, h)| (*id, h))
+    /// Rejects IDs whose string fails `validate`.
+    ///
+    /// Runs on the ID string extracted from the filename, before
+    /// [`FromFilename::from_filename`],
+    /// e.g. to enforce lowercase names without spaces. Rejected files are
+    /// skipped with [`SkipReason::InvalidIdChars`] and logged as warnings.
+    #[must_use]
+    pub fn validate_id_charset(mut self, validate: fn(&str) -> bool) -> Self {
+        self.validate_id = Some(validate);
+        self
     }
 
-    /// Returns a mutable iterator over all IDs and their handles.
-    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Id, &mut Handle<A>)> + '_ {
-        self.assets.iter_mut().map(|(id, h)| (*id, h))
+    /// Only loads files whose name matches the glob `pattern`.
+    ///
+    /// `*` matches any run of characters, e.g. `boss_*.actor.ron`. The
+    /// pattern is matched against the file name before the ID is derived.
+    /// Can be called several times; a file is then included if it matches
+    /// any of the patterns. Other files are skipped with
+    /// [`SkipReason::Filtered`].
+    #[must_use]
+    pub fn with_include_pattern(mut self, pattern: &str) -> Self {
+        self.include_patterns.push(pattern.to_string());
+        self
     }
 
-    /// Direct access to underlying HashMap.
+    /// Skips files whose name matches the glob `pattern`, e.g. `*_wip.*`.
+    ///
+    /// Can be called several times, and takes precedence over
+    /// [`Self::with_include_pattern`]. Excluded files are skipped with
+    /// [`SkipReason::Filtered`].
     #[must_use]
-    pub fn assets(&self) -> &HashMap<Id, Handle<A>> {
-        &self.assets
+    pub fn with_exclude_pattern(mut self, pattern: &str) -> Self {
+        self.exclude_patterns.push(pattern.to_string());
+        self
     }
 
-    /// Mutable access to underlying HashMap.
+    /// Sets the filename prefixes that keep a file out of the library
+    /// (default `['.', '_']`).
+    ///
+    /// For example `&['.', '~']` skips `~draft.spell.ron` while loading
+    /// `_base.spell.ron`. A skipped file with a `.` prefix is reported as
+    /// [`SkipReason::Hidden`], any other as [`SkipReason::Disabled`].
     #[must_use]
-    pub fn assets_mut(&mut self) -> &mut HashMap<Id, Handle<A>> {
-        &mut self.assets
+    pub fn skip_prefixes(mut self, prefixes: &'static [char]) -> Self {
+        self.skip_prefixes = prefixes;
+        self
     }
-}
 
-// =============================================================================
-// Loading System
-// =============================================================================
+    /// Derives IDs with `parser` instead of the built-in filename rules.
+    ///
+    /// The parser is called with the asset path of every file matching the
+    /// configured extension, including hidden, disabled and nested ones, and
+    /// skips the file by returning `None`. This allows IDs such as
+    /// `weapons/sword@v2` or stripping a numeric ordering prefix. Locale
+    /// variants, subtypes, numeric keys and the charset check operate on the
+    /// built-in ID string and don't apply to parsed IDs.
+    #[must_use]
+    pub fn with_id_parser(
+        mut self,
+        parser: Box<dyn Fn(&Path) -> Option<Id> + Send + Sync>,
+    ) -> Self {
+        self.id_parser = Some(parser.into());
+        self
+    }
 
-/// Generic system that loads assets from folders.
-///
-/// This system:
-/// 1. Initiates folder loading via AssetServer::load_folder
-/// 2. Waits for the LoadedFolder to be available
-/// 3. Processes all handles, extracting IDs from filenames
-/// 4. Populates the AssetFolder with ID -> Handle mappings
-fn load_assets_from_folder<Id, A>(
-    asset_server: Res<AssetServer>,
-    config: Res<FolderLoaderConfig<Id, A>>,
-    mut folder_handle: ResMut<AssetFolderHandle<A>>,
-    loaded_folders: Res<Assets<LoadedFolder>>,
-    mut library: ResMut<AssetFolder<Id, A>>,
-) where
-    Id: Clone + Copy + Eq + Hash + Send + Sync + Default + From<String> + std::fmt::Debug + 'static,
-    A: Asset + Clone + Send + Sync + 'static,
-{
-    // Start loading the folder if we haven't yet
-    if folder_handle.handle.is_none() {
-        folder_handle.handle = Some(asset_server.load_folder(config.folder_path));
-        return;
+    /// Rejects assets for which `validator` returns an error.
+    ///
+    /// Runs on the asset data once it has loaded (after merging folder
+    /// defaults) and before it is registered, including for files added at
+    /// runtime. Rejected files stay out of the library and are reported
+    /// like failed loads, as [`FolderLoadError::ValidationFailed`] with the
+    /// returned message.
+    #[must_use]
+    pub fn with_validator(
+        mut self,
+        validator: Box<dyn Fn(&A) -> Result<(), String> + Send + Sync>,
+    ) -> Self {
+        self.validator = Some(validator.into());
+        self
     }
 
-    // Skip if already processed
-    if folder_handle.processed {
-        return;
+    /// Loads previews of type `P` lazily, next to each asset file.
+    ///
+    /// The preview of `fireball.spell.ron` with extension `.png` is
+    /// `fireball.png` in the same directory. Nothing is loaded until
+    /// [`AssetFolder::request_preview`] is called for an ID; the handle is
+    /// then stored in a parallel `AssetFolder<Id, P>` resource. Since
+    /// `AssetServer::load_folder` loads every file it can, combine this with
+    /// [`Self::two_pass`] (which only loads matching files) to keep previews
+    /// unloaded until requested.
+    #[must_use]
+    pub fn with_preview<P: Asset + Clone>(mut self, preview_extension: &'static str) -> Self
+    where
+        Id: std::fmt::Debug,
+    {
+        self.preview = Some((preview_extension, add_preview_support::<Id, A, P>));
+        self
     }
 
-    // Wait for folder to be loaded
-    let Some(folder_handle_ref) = &folder_handle.handle else {
-        return;
-    };
-    let Some(folder) = loaded_folders.get(folder_handle_ref) else {
-        return;
-    };
+    /// Registers the loader's resources and components for reflection.
+    ///
+    /// Adds [`AssetFolder`], [`AssetFolderHandle`] and [`AssetRef`] to the
+    /// type registry, so inspectors and debug overlays can show them. The
+    /// handle map itself isn't reflectable, so the library's registered IDs
+    /// are mirrored into a reflected `ids` list, in source path order, on
+    /// every change. Requires a reflectable ID type.
+    #[must_use]
+    pub fn with_reflection(mut self) -> Self
+    where
+        Id: FromReflect + Typed + GetTypeRegistration,
+    {
+        self.register_reflection = Some(register_reflection::<Id, A>);
+        self
+    }
 
-    // Process all handles at once
-    for handle in &folder.handles {
-        let Some(path) = handle.path() else {
-            continue;
-        };
+    /// Sets the ordering weight used by [`AssetFolder::iter_by_sort_key`].
+    ///
+    /// Lets content control menu order through a field such as `sort_order`
+    /// instead of the alphabetical ID order.
+    #[must_use]
+    pub fn with_sort_key_fn(mut self, sort_key: fn(&A) -> i64) -> Self {
+        self.sort_key = Some(sort_key);
+        self
+    }
 
-        // Extract ID from filename
-        let Some(id) = id_from_filename_with_extension::<Id>(path.path(), config.file_extension)
-        else {
-            continue;
-        };
+    /// Registers a file with an empty stem under `Id::default()`.
+    ///
+    /// By default a file named exactly like the extension (e.g. `.spell.ron`)
+    /// is skipped. When enabled it becomes a sentinel "default" entry. Only
+    /// one such file can exist per folder; any file whose name converts to
+    /// the same ID as `Id::default()` collides with it as a duplicate.
+    ///
+    /// Bevy's file source doesn't list dot-files, so with extensions starting
+    /// with `.` the file is only found up front with [`Self::two_pass`],
+    /// which looks for it directly in each folder. Otherwise it has to be
+    /// loaded explicitly (e.g. `asset_server.load("prefabs/spells/.spell.ron")`)
+    /// and is then picked up like any asset added to the folder.
+    #[must_use]
+    pub fn allow_empty_id(mut self, enabled: bool) -> Self {
+        self.allow_empty_id = enabled;
+        self
+    }
 
-        // Get typed handle and register it
-        let typed_handle: Handle<A> = handle.clone().typed();
-        library.insert(id, typed_handle);
+    /// Discovers the folder contents before loading any asset.
+    ///
+    /// `AssetServer::load_folder` only resolves once every file has loaded.
+    /// In two-pass mode the folder is listed through the default asset source
+    /// first, so [`AssetFolderHandle::total`] is known during
+    /// [`Phase::Loading`], and only matching files are then loaded. Folders
+    /// on other asset sources aren't supported in this mode.
+    #[must_use]
+    pub fn two_pass(mut self, enabled: bool) -> Self {
+        self.two_pass = enabled;
+        self
+    }
 
-        debug!(
-            "Registered asset handle: {:?} ({})",
-            id,
-            path.path().display()
-        );
+    /// Extracts the IDs of a resolved folder on a background task.
+    ///
+    /// For folders with thousands of files, extracting and classifying every
+    /// ID in one frame can cause a hitch. With this enabled the file paths
+    /// are handed to the `AsyncComputeTaskPool` once the `LoadedFolder`
+    /// resolves, and the loading system polls the task from the next frame
+    /// on. Only ID extraction moves: the per-entry bookkeeping that needs
+    /// the `AssetServer` or `Assets<A>` (type checks, duplicates, load
+    /// states, registering entries in the [`AssetFolder`]) runs on the main
+    /// thread once the task yields, so discovery finishes at least a frame
+    /// later than without it. Has no effect with [`Self::two_pass`], whose
+    /// listing already runs on a task.
+    #[must_use]
+    pub fn async_id_extraction(mut self, enabled: bool) -> Self {
+        self.async_id_extraction = enabled;
+        self
     }
 
-    // Mark as processed
-    folder_handle.processed = true;
+    /// Assigns each ID a stable `u64` key for compact network references.
+    ///
+    /// The key is [`stable_id_hash`] of the ID string, so it is identical
+    /// across runs and platforms. Look keys up with
+    /// [`AssetFolder::numeric_key`] and [`AssetFolder::id_from_numeric`].
+    #[must_use]
+    pub fn with_numeric_keys(mut self, enabled: bool) -> Self {
+        self.numeric_keys = enabled;
+        self
+    }
 
-    info!(
-        "Processed {} asset handles from folder '{}'",
-        library.len(),
-        config.folder_path
-    );
-}
-
-// =============================================================================
-// ID Extraction Utilities
-// =============================================================================
-
-/// Extracts an ID from a filename by stripping the extension.
-///
-/// # Arguments
-///
-/// * `path` - The full path to the asset file
-/// * `extension` - The extension to strip (e.g., ".spell.ron")
-///
-/// # Returns
-///
-/// The ID if the filename matches the extension and is valid,
-/// or `None` if:
-/// - The file doesn't have the expected extension
-/// - The filename starts with `.` (hidden file)
-/// - The filename starts with `_` (disabled file)
-pub fn id_from_filename_with_extension<Id>(path: &Path, extension: &str) -> Option<Id>
-where
-    Id: From<String>,
-{
-    let filename = path.file_name()?.to_string_lossy();
-
-    // Check if filename has the expected extension
-    if !filename.ends_with(extension) {
-        return None;
+    /// Sets how files sharing an ID are resolved (default
+    /// [`DuplicatePolicy::Warn`]).
+    ///
+    /// Every collision is recorded in [`AssetFolderHandle::report`] and
+    /// [`AssetFolderHandle::errors`] regardless of the policy.
+    #[must_use]
+    pub fn on_duplicate(mut self, policy: DuplicatePolicy) -> Self {
+        self.duplicate_policy = policy;
+        self
     }
 
-    // Strip extension to get the ID string
-    let id_str = filename.strip_suffix(extension)?;
+    /// Drops the first `count` subfolders from recursive IDs.
+    ///
+    /// With `id_base_strip(1)`, `spells/fire/arcane/bolt.spell.ron` becomes
+    /// `arcane/bolt` instead of `fire/arcane/bolt`. Files with fewer
+    /// subfolders keep only their stem. The default of `0` keeps the full
+    /// relative path; without [`Self::new_recursive`] IDs are the plain stem
+    /// anyway. `max_depth` still counts every subfolder.
+    #[must_use]
+    pub fn id_base_strip(mut self, count: usize) -> Self {
+        self.id_base_strip = count;
+        self
+    }
 
-    // Skip hidden files (starting with .)
-    if id_str.starts_with('.') {
-        return None;
+    /// Matches the file extension regardless of ASCII case.
+    ///
+    /// `fireball.Spell.Ron` and `fireball.SPELL.RON` then match
+    /// `".spell.ron"`. The ID keeps the original casing of the stem, so
+    /// `FireBall.Spell.ron` registers `FireBall`. Bevy picks asset loaders by
+    /// exact extension, so combine this with [`Self::two_pass`], which loads
+    /// files by asset type instead.
+    #[must_use]
+    pub fn case_insensitive(mut self) -> Self {
+        self.case_insensitive = true;
+        self
     }
 
-    // Skip disabled files (starting with _)
-    if id_str.starts_with('_') {
-        return None;
+    /// Splits filename stems into an ID and a subtype tag at `delimiter`.
+    ///
+    /// With `'@'`, `fireball@offensive.spell.ron` registers ID `fireball`
+    /// with subtype `offensive`, available from [`AssetFolder::subtype`].
+    /// Files without the delimiter have no subtype, and files starting with
+    /// it are skipped. The split happens at the
+    /// first delimiter, and the charset check of
+    /// [`Self::validate_id_charset`] only sees the ID part.
+    #[must_use]
+    pub fn with_id_subtype_delimiter(mut self, delimiter: char) -> Self {
+        self.subtype_delimiter = Some(delimiter);
+        self
     }
 
-    // Skip empty IDs
-    if id_str.is_empty() {
-        return None;
+    /// Compacts the library with [`AssetFolder::compact`] once a load completes.
+    #[must_use]
+    pub fn compact_on_load(mut self, enabled: bool) -> Self {
+        self.compact_on_load = enabled;
+        self
     }
 
-    Some(Id::from(id_str.to_string()))
-}
+    /// Runs the loading systems in `schedule` instead of `Update`.
+    ///
+    /// E.g. `FixedUpdate` to load at a fixed rate, or `PreUpdate` so the
+    /// library is current before gameplay systems run. Once the folder has
+    /// been processed, the main loading system is skipped by a run condition
+    /// until a reload, retry or unload is requested.
+    #[must_use]
+    pub fn in_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = schedule.intern();
+        self
+    }
 
-/// Legacy function for backwards compatibility.
-/// Extracts an ID from a filename using extension from path itself.
-pub fn id_from_filename<Id>(path: &Path, extension: &str) -> Option<Id>
-where
-    Id: From<String>,
-{
-    id_from_filename_with_extension(path, extension)
-}
+    /// Keeps the library in sync with the folder on disk after loading.
+    ///
+    /// Bevy reloads the `LoadedFolder` when files are added to or removed
+    /// from the folder. The loader then registers new IDs without clearing
+    /// existing ones and drops the IDs of removed files. Edited files are
+    /// reloaded in place by Bevy and keep their handles. Requires Bevy's
+    /// `file_watcher` feature with change watching enabled in the
+    /// `AssetPlugin`, and has no effect with [`Self::two_pass`] or
+    /// [`Self::with_manifest`], which don't load the folder as a whole.
+    #[must_use]
+    pub fn hot_reload(mut self, enabled: bool) -> Self {
+        self.hot_reload = enabled;
+        self
+    }
 
-/// Check if a path represents a hidden or disabled file.
-#[must_use]
-pub fn is_hidden_file(path: &Path) -> bool {
-    path.file_name()
-        .map(|name| {
-            let name_str = name.to_string_lossy();
-            name_str.starts_with('.') || name_str.starts_with('_')
-        })
-        .unwrap_or(false)
-}
+    /// Keeps the `Handle<LoadedFolder>` alive after the folder is processed
+    /// (default `true`).
+    ///
+    /// The folder handle holds every file Bevy loaded from the folder,
+    /// including skipped files and files of other asset types, so they stay
+    /// resident along with the library's assets. This guards against assets
+    /// being unloaded once only the typed handles remain, at the cost of
+    /// memory for files the library doesn't use. When disabled, the handle
+    /// is released once the folder has been processed, and
+    /// [`AssetFolderHandle::raw_handle_count`] returns `None` afterwards.
+    /// [`Self::hot_reload`] always keeps the handle.
+    #[must_use]
+    pub fn retain_folder_handle(mut self, enabled: bool) -> Self {
+        self.retain_folder_handle = enabled;
+        self
+    }
 
-// =============================================================================
-// AtlasIcon
-// =============================================================================
+    /// Reports the remaining download size of a remote asset source through
+    /// [`AssetFolderHandle::pending_bytes`].
+    ///
+    /// Bevy's asset sources don't report transfer progress, so the source's
+    /// `AssetReader` feeds `counter` from the content lengths it knows about;
+    /// the loader mirrors it into the handle each frame while loading.
+    #[must_use]
+    pub fn with_pending_bytes(mut self, counter: PendingBytes) -> Self {
+        self.pending_bytes = Some(counter);
+        self
+    }
 
-/// Icon rendering data from a texture atlas slice.
-///
-/// Contains all the handles and indices needed to render an icon from
-/// an atlas-based spritesheet.
-#[derive(Debug, Default, Clone, PartialEq)]
-pub struct AtlasIcon {
-    /// The atlas image handle.
-    pub image: Handle<Image>,
-    /// The texture atlas layout handle.
-    pub layout: Handle<TextureAtlasLayout>,
-    /// The atlas index for this icon's slice.
-    pub atlas_index: usize,
-}
+    /// Keeps the live library unchanged until a reload completes.
+    ///
+    /// Reloaded assets accumulate in a staging library that is swapped into
+    /// the [`AssetFolder`] at once when the reload finishes, so systems never
+    /// see a partially reloaded folder.
+    #[must_use]
+    pub fn staged_reloads(mut self, enabled: bool) -> Self {
+        self.staged_reloads = enabled;
+        self
+    }
 
-impl AtlasIcon {
-    /// Creates a new AtlasIcon.
+    /// Loads per-locale variants of each asset.
+    ///
+    /// The last dot-separated segment of the ID is parsed as a locale, so with
+    /// extension `.text.ron` both `greeting.en.text.ron` and
+    /// `greeting.fr.text.ron` map to the ID `greeting`. The library stores all
+    /// variants and resolves each ID to `current_locale`, falling back to
+    /// `fallback_locale`, then to a locale-less file (`greeting.text.ron`)
+    /// and finally, with a warning, to the alphabetically first variant.
+    /// Use [`AssetFolder::set_locale`] to switch at runtime.
     #[must_use]
-    pub fn new(
-        image: Handle<Image>,
-        layout: Handle<TextureAtlasLayout>,
-        atlas_index: usize,
+    pub fn with_locale_variants(
+        mut self,
+        current_locale: &'static str,
+        fallback_locale: &'static str,
     ) -> Self {
-        Self {
-            image,
-            layout,
-            atlas_index,
-        }
+        self.locales = Some((current_locale, fallback_locale));
+        self
     }
 
-    /// Returns a clone of the underlying image handle for UI usage.
+    /// Extracts IDs from filenames with a regular expression.
+    ///
+    /// The pattern is matched against the full filename (including the
+    /// extension) of each file that passes the usual extension and prefix
+    /// checks, and the ID is taken from the capture group named `id`.
+    /// Files that don't match are skipped. For example
+    /// `^spell_(?P<id>\d+)\.ron$` turns `spell_0042.ron` into `0042`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern is not a valid regex or has no `id` group.
+    #[cfg(feature = "regex")]
     #[must_use]
-    pub fn get_image(&self) -> Handle<Image> {
-        self.image.clone()
+    pub fn with_id_regex(mut self, pattern: &str) -> Self {
+        let regex = regex::Regex::new(pattern)
+            .unwrap_or_else(|err| panic!("invalid ID regex '{pattern}': {err}"));
+        assert!(
+            regex.capture_names().flatten().any(|name| name == "id"),
+            "ID regex '{pattern}' has no capture group named `id`"
+        );
+        self.id_regex = Some(regex);
+        self
     }
 
-    /// Returns the texture atlas configuration for this icon.
+    /// Takes the files and their IDs from a manifest instead of the folder.
+    ///
+    /// `path` is the asset path of a RON list of entries such as
+    /// `(id: "fireball", file: "fb.spell.ron")`, with `file` relative to the
+    /// loader's folder. Only the listed files are loaded, each under its
+    /// listed ID, so content authors can rename files without changing IDs;
+    /// the filename rules (extensions, prefixes, patterns) don't apply. An
+    /// entry whose file is missing is recorded as a failure, and a manifest
+    /// that can't be read or parsed fails the whole load. Files added to the
+    /// folder later aren't picked up, so [`Self::hot_reload`] has no effect.
+    /// Requires the `manifest` feature.
+    #[cfg(feature = "manifest")]
     #[must_use]
-    pub fn texture_atlas(&self) -> TextureAtlas {
-        TextureAtlas {
-            layout: self.layout.clone(),
-            index: self.atlas_index,
+    pub fn with_manifest(mut self, path: &'static str) -> Self {
+        self.manifest = Some(path);
+        self
+    }
+
+    /// Resets this loader's resources to their pristine state.
+    ///
+    /// Clears the [`AssetFolder`] contents and disabled IDs and replaces the
+    /// [`AssetFolderHandle`] (including its counts and report), keeping the
+    /// library settings from the plugin. The folder loads again from scratch
+    /// on the next update, which isolates test cases sharing an `App`.
+    pub fn reset(world: &mut World) {
+        if let Some(mut library) = world.get_resource_mut::<AssetFolder<Id, A>>() {
+            library.clear_loaded();
+            library.disabled.clear();
+            library.frozen = false;
+            library.staging = None;
+        }
+        if let Some(mut folder_handle) = world.get_resource_mut::<AssetFolderHandle<A>>() {
+            *folder_handle = AssetFolderHandle::new();
+        }
+        if let Some(mut priorities) = world.get_resource_mut::<FolderLoadPriorities>() {
+            priorities.set_processed::<A>(false);
         }
     }
+}
 
-    /// Creates an ImageNode from this icon.
-    #[must_use]
-    pub fn image_node(&self) -> ImageNode {
-        ImageNode::from_atlas_image(self.image.clone(), self.texture_atlas())
+impl<Id, A> FolderLoaderPlugin<Id, A>
+where
+    Id: Clone + Eq + Hash + Send + Sync + Default + FromFilename + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
+{
+    /// The loader configuration, without the folders' disk paths.
+    fn config(&self) -> FolderLoaderConfig<Id, A> {
+        FolderLoaderConfig {
+            folder_path: self.folder_path,
+            extra_folders: self.extra_folders.clone(),
+            file_extensions: self.file_extensions.clone(),
+            priority: self.priority,
+            on_progress: self.on_progress,
+            limit: self.limit,
+            concurrency_hint: self.concurrency_hint,
+            #[cfg(feature = "regex")]
+            id_regex: self.id_regex.clone(),
+            #[cfg(feature = "manifest")]
+            manifest: self.manifest,
+            locales: self.locales,
+            on_failure: self.on_failure,
+            dependency_fn: self.dependency_fn,
+            wait_for_dependencies: self.wait_for_dependencies,
+            staged_reloads: self.staged_reloads,
+            compact_on_load: self.compact_on_load,
+            hot_reload: self.hot_reload,
+            retain_folder_handle: self.retain_folder_handle,
+            pending_bytes: self.pending_bytes.clone(),
+            allow_empty_id: self.allow_empty_id,
+            two_pass: self.two_pass,
+            async_id_extraction: self.async_id_extraction,
+            on_skip: self.on_skip,
+            numeric_keys: self.numeric_keys,
+            folder_defaults: self.folder_defaults,
+            validate_id: self.validate_id,
+            include_patterns: self.include_patterns.clone(),
+            exclude_patterns: self.exclude_patterns.clone(),
+            skip_prefixes: self.skip_prefixes,
+            id_parser: self.id_parser.clone(),
+            validator: self.validator.clone(),
+            subtype_delimiter: self.subtype_delimiter,
+            recursive: self.recursive,
+            max_depth: self.max_depth,
+            id_base_strip: self.id_base_strip,
+            case_insensitive: self.case_insensitive,
+            duplicate_policy: self.duplicate_policy,
+            #[cfg(not(target_arch = "wasm32"))]
+            disk_paths: Vec::new(),
+            _marker: PhantomData,
+        }
     }
 }
 
-// =============================================================================
-// Parsing Utilities
-// =============================================================================
+impl<Id, A> Plugin for FolderLoaderPlugin<Id, A>
+where
+    Id: Clone + Eq + Hash + Send + Sync + Default + FromFilename + std::fmt::Debug + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
+{
+    fn build(&self, app: &mut App) {
+        // Bevy rejects the exact same plugin type twice, but a second ID type
+        // for the same asset type shares the load state. Loading a folder the
+        // first one already loads would only fight it over its resources, so
+        // the whole plugin is skipped; otherwise it runs with a warning.
+        let folders: Vec<_> = std::iter::once(self.folder_path)
+            .chain(self.extra_folders.iter().copied())
+            .map(|folder| (TypeId::of::<A>(), folder.trim_end_matches('/')))
+            .collect();
+        let mut registered = app.world_mut().get_resource_or_init::<RegisteredFolders>();
+        if let Some((_, folder)) = folders.iter().find(|key| registered.0.contains(key)) {
+            warn!(
+                "[{}] Folder '{folder}' is already loaded for this asset type; \
+                 skipping the duplicate FolderLoaderPlugin with ID type {}",
+                type_name::<A>(),
+                type_name::<Id>()
+            );
+            return;
+        }
+        if registered
+            .0
+            .iter()
+            .any(|(type_id, _)| *type_id == TypeId::of::<A>())
+        {
+            warn!(
+                "[{}] Another FolderLoaderPlugin already loads this asset type; \
+                 '{}' with ID type {} will share its AssetFolderHandle and conflict",
+                type_name::<A>(),
+                self.folder_path,
+                type_name::<Id>()
+            );
+        }
+        registered.0.extend(folders);
 
-/// Deserializes a string field to `Option<String>`.
-/// Accepts a bare string and converts empty strings to `None`.
+        #[cfg(feature = "manifest")]
+        if self.hot_reload
+            && let Some(manifest) = self.manifest
+        {
+            warn!(
+                "[{}] Hot reload has no effect on '{}', which loads the files \
+                 listed in the manifest '{manifest}'",
+                type_name::<A>(),
+                self.folder_path
+            );
+        }
+
+        // Resolve the folders on disk for modification time checks
+        #[cfg(not(target_arch = "wasm32"))]
+        let disk_paths = self
+            .reload_only_if_modified
+            .then(|| {
+                let plugin = app.get_added_plugins::<AssetPlugin>().into_iter().next()?;
+                let root =
+                    bevy::asset::io::file::FileAssetReader::get_base_path().join(&plugin.file_path);
+                Some(
+                    std::iter::once(self.folder_path)
+                        .chain(self.extra_folders.iter().copied())
+                        .map(|folder| root.join(folder))
+                        .collect(),
+                )
+            })
+            .flatten()
+            .unwrap_or_default();
+
+        // Store config in a resource
+        app.insert_resource(SharedConfig(Arc::new(FolderLoaderConfig {
+            #[cfg(not(target_arch = "wasm32"))]
+            disk_paths,
+            ..self.config()
+        })));
+
+        // Register with the shared priority coordinator and progress
+        app.world_mut()
+            .get_resource_or_init::<FolderLoadPriorities>()
+            .register::<A>(self.priority);
+        app.world_mut()
+            .get_resource_or_init::<FolderLoadProgress>()
+            .register::<A>();
+
+        // Initialize resources
+        app.init_asset::<A>();
+        app.init_resource::<AssetFolderHandle<A>>();
+        app.init_resource::<AssetFolder<Id, A>>();
+        app.add_message::<FolderDiscovered<A>>();
+        app.add_message::<FolderLoadCompleted<A>>();
+        app.add_message::<AssetRegistered<Id, A>>();
+        app.add_message::<UnloadFolder<A>>();
+        let fallback = self
+            .fallback_path
+            .map(|path| app.world().resource::<AssetServer>().load(path));
+        let mut library = app.world_mut().resource_mut::<AssetFolder<Id, A>>();
+        library.folder_path = self.folder_path;
+        library.file_extension = self.file_extensions[0];
+        library.set_ready_at_count(self.ready_at_count);
+        if let Some(fallback) = fallback {
+            library.set_fallback(fallback);
+        }
+        if let Some(sort_key) = self.sort_key {
+            library.set_sort_key_fn(sort_key);
+        }
+        if let Some((current, fallback)) = self.locales {
+            library.set_fallback_locale(fallback);
+            library.set_locale(current);
+        }
+
+        if let Some(register_reflection) = self.register_reflection {
+            register_reflection(app);
+        }
+        if let Some(register_loader) = self.register_loader {
+            register_loader(app, &self.file_extensions);
+        }
+        if let Some((preview_extension, add_preview)) = self.preview {
+            add_preview(
+                app,
+                self.schedule,
+                self.file_extensions.clone(),
+                preview_extension,
+            );
+        }
+
+        // Add the loading and hot-add systems
+        let systems = (
+            load_assets_from_folder::<Id, A>.run_if(folder_needs_loading::<A>),
+            register_added_assets::<Id, A>,
+            sync_reloaded_folder::<Id, A>,
+            update_folder_progress::<A>,
+        )
+            .chain()
+            .into_configs();
+        #[cfg(feature = "state")]
+        if let Some(state_gate) = &self.state_gate {
+            state_gate(app, self.schedule, systems);
+            return;
+        }
+        app.add_systems(self.schedule, systems);
+    }
+}
+
+/// Asset type and folder pairs claimed by a [`FolderLoaderPlugin`], with
+/// trailing slashes trimmed so `"spells"` and `"spells/"` are one folder.
+#[derive(Resource, Default)]
+struct RegisteredFolders(HashSet<(TypeId, &'static str)>);
+
+/// Configuration resource of a folder loader.
 ///
-/// # Example
+/// Shared with the background task of
+/// [`FolderLoaderPlugin::async_id_extraction`] instead of being cloned.
+#[derive(Resource, Deref)]
+struct SharedConfig<Id, A>(Arc<FolderLoaderConfig<Id, A>>)
+where
+    Id: Clone + Eq + Hash + Send + Sync + Default + FromFilename + 'static,
+    A: Asset + Clone + Send + Sync + 'static;
+
+/// Sets up preview loading, see [`FolderLoaderPlugin::with_preview`].
+type AddPreview = fn(&mut App, InternedScheduleLabel, Vec<&'static str>, &'static str);
+
+/// A user-supplied filename-to-ID parser, see [`FolderLoaderPlugin::with_id_parser`].
+type IdParser<Id> = std::sync::Arc<dyn Fn(&Path) -> Option<Id> + Send + Sync>;
+
+/// A user-supplied asset check, see [`FolderLoaderPlugin::with_validator`].
+type AssetValidator<A> = std::sync::Arc<dyn Fn(&A) -> Result<(), String> + Send + Sync>;
+
+/// The loading and hot-add systems of a folder loader.
+#[cfg(feature = "state")]
+type LoaderSystems = bevy::ecs::schedule::ScheduleConfigs<bevy::ecs::system::ScheduleSystem>;
+
+/// How a folder loader resolves two files that produce the same ID.
 ///
-/// ```rust
-/// use serde::Deserialize;
-/// use msg_load_folder::deserialize_optional_string;
+/// "First" and "last" refer to the order files are considered in: sorted by
+/// path within each folder, folder by folder. The outcome therefore doesn't
+/// depend on the order the filesystem lists files in. Files added after the
+/// folder loaded come after every existing file.
 ///
-/// #[derive(Deserialize)]
-/// struct MyData {
-///     #[serde(default, deserialize_with = "deserialize_optional_string")]
-///     atlas_slice: Option<String>,
-/// }
-/// ```
-pub fn deserialize_optional_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+/// Set with [`FolderLoaderPlugin::on_duplicate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DuplicatePolicy {
+    /// Keep the file seen first, logging the collision at debug level.
+    KeepFirst,
+    /// Keep the file seen last, logging the collision at debug level.
+    KeepLast,
+    /// Keep the file seen last and log a warning.
+    #[default]
+    Warn,
+}
+
+/// Configuration for folder loading.
+#[derive(Clone)]
+struct FolderLoaderConfig<Id, A>
 where
-    D: serde::Deserializer<'de>,
+    Id: Clone + Eq + Hash + Send + Sync + Default + FromFilename + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
 {
-    use serde::Deserialize;
-    let s = String::deserialize(deserializer)?;
-    Ok(if s.is_empty() { None } else { Some(s) })
+    folder_path: &'static str,
+    extra_folders: Vec<&'static str>,
+    file_extensions: Vec<&'static str>,
+    priority: i32,
+    on_progress: Option<fn(usize, usize)>,
+    limit: Option<usize>,
+    concurrency_hint: Option<usize>,
+    #[cfg(feature = "regex")]
+    id_regex: Option<regex::Regex>,
+    #[cfg(feature = "manifest")]
+    manifest: Option<&'static str>,
+    locales: Option<(&'static str, &'static str)>,
+    on_failure: Option<fn(Id, &AssetServer) -> Option<Handle<A>>>,
+    dependency_fn: Option<fn(&A) -> Vec<String>>,
+    wait_for_dependencies: bool,
+    staged_reloads: bool,
+    compact_on_load: bool,
+    hot_reload: bool,
+    retain_folder_handle: bool,
+    pending_bytes: Option<PendingBytes>,
+    allow_empty_id: bool,
+    two_pass: bool,
+    async_id_extraction: bool,
+    on_skip: Option<fn(&Path, SkipReason)>,
+    numeric_keys: bool,
+    folder_defaults: Option<(&'static str, fn(&mut A, &A))>,
+    validate_id: Option<fn(&str) -> bool>,
+    include_patterns: Vec<String>,
+    exclude_patterns: Vec<String>,
+    skip_prefixes: &'static [char],
+    id_parser: Option<IdParser<Id>>,
+    validator: Option<AssetValidator<A>>,
+    subtype_delimiter: Option<char>,
+    recursive: bool,
+    max_depth: Option<usize>,
+    id_base_strip: usize,
+    case_insensitive: bool,
+    duplicate_policy: DuplicatePolicy,
+    /// Folder locations on disk, set when reloads check modification times.
+    #[cfg(not(target_arch = "wasm32"))]
+    disk_paths: Vec<PathBuf>,
+    _marker: PhantomData<(Id, A)>,
 }
 
-// =============================================================================
-// Tests
-// =============================================================================
+impl<Id, A> FolderLoaderConfig<Id, A>
+where
+    Id: Clone + Eq + Hash + Send + Sync + Default + FromFilename + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
+{
+    /// Latest modification time in the folders on disk, if they are checked.
+    fn last_modified(&self) -> Option<SystemTime> {
+        #[cfg(not(target_arch = "wasm32"))]
+        return self
+            .disk_paths
+            .iter()
+            .filter_map(|disk_path| latest_modification(disk_path))
+            .max();
+        #[cfg(target_arch = "wasm32")]
+        None
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// All configured folders, in load order.
+    fn folders(&self) -> impl Iterator<Item = &'static str> + '_ {
+        std::iter::once(self.folder_path).chain(self.extra_folders.iter().copied())
+    }
 
-    // Mock ID type for testing
-    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
-    struct MockId(u64);
+    /// Dot-file names that map to `Id::default()` with `allow_empty_id`.
+    fn empty_id_files(&self) -> Vec<&'static str> {
+        self.file_extensions
+            .iter()
+            .copied()
+            .filter(|extension| self.allow_empty_id && extension.starts_with('.'))
+            .collect()
+    }
 
-    impl From<String> for MockId {
-        fn from(s: String) -> Self {
-            MockId(s.len() as u64)
+    /// The first configured extension a file ends with.
+    fn extension_of(&self, path: &Path) -> Option<&'static str> {
+        let filename = path.file_name()?.to_str()?;
+        self.file_extensions.iter().copied().find(|extension| {
+            if !self.case_insensitive {
+                return filename.ends_with(extension);
+            }
+            filename
+                .len()
+                .checked_sub(extension.len())
+                .filter(|&split| filename.is_char_boundary(split))
+                .is_some_and(|split| filename[split..].eq_ignore_ascii_case(extension))
+        })
+    }
+
+    /// The path with its extension spelled as configured, if it matches
+    /// case-insensitively.
+    fn normalized_path<'a>(&self, path: &'a Path) -> Cow<'a, Path> {
+        if !self.case_insensitive {
+            return path.into();
         }
+        let (Some(filename), Some(extension)) = (
+            path.file_name().and_then(|name| name.to_str()),
+            self.extension_of(path),
+        ) else {
+            return path.into();
+        };
+        let stem = &filename[..filename.len() - extension.len()];
+        path.with_file_name(format!("{stem}{extension}")).into()
     }
 
-    #[test]
-    fn test_id_from_filename_valid() {
-        let path = Path::new("test_item.mock.ron");
-        let id: Option<MockId> = id_from_filename_with_extension(path, ".mock.ron");
-        assert!(id.is_some());
+    /// Derive the ID string for a file according to the configured strategy.
+    ///
+    /// Borrows from `path` unless the ID has to be built, e.g. from
+    /// subfolders or a case-normalized filename.
+    fn id_string_for<'a>(&self, path: &'a Path) -> Option<Cow<'a, str>> {
+        match self.normalized_path(path) {
+            Cow::Borrowed(path) => self.normalized_id_string_for(path),
+            Cow::Owned(path) => self
+                .normalized_id_string_for(&path)
+                .map(|id| Cow::Owned(id.into_owned())),
+        }
+    }
+
+    /// [`Self::id_string_for`] on an already normalized path.
+    fn normalized_id_string_for<'a>(&self, path: &'a Path) -> Option<Cow<'a, str>> {
+        if self.is_too_deep(path) {
+            return None;
+        }
+        let extension = self.extension_of(path)?;
+        #[cfg(feature = "regex")]
+        if let Some(regex) = &self.id_regex {
+            let id = regex_id_str(path, extension, regex, self.skip_prefixes)?;
+            return Some(self.with_subfolders(path, id));
+        }
+        let id = filename_id_str(path, extension, self.skip_prefixes)?;
+        Some(self.with_subfolders(path, id))
+    }
+
+    /// The subfolders between the loaded folder and a file.
+    fn subfolders<'a>(&self, path: &'a Path) -> Vec<Cow<'a, str>> {
+        let Some(parent) = self
+            .folders()
+            .find_map(|folder| path.strip_prefix(folder).ok())
+            .and_then(Path::parent)
+        else {
+            return Vec::new();
+        };
+        parent
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect()
+    }
+
+    /// Prefix an ID with the file's subfolders in recursive mode.
+    fn with_subfolders<'a>(&self, path: &'a Path, id: Cow<'a, str>) -> Cow<'a, str> {
+        if !self.recursive {
+            return id;
+        }
+        let mut parts = self.subfolders(path);
+        parts.drain(..self.id_base_strip.min(parts.len()));
+        if parts.is_empty() {
+            return id;
+        }
+        parts.push(id);
+        parts.join("/").into()
+    }
+
+    /// Check if a file is nested deeper than the recursive `max_depth`.
+    fn is_too_deep(&self, path: &Path) -> bool {
+        self.recursive
+            && self
+                .max_depth
+                .is_some_and(|max_depth| self.subfolders(path).len() > max_depth)
+    }
+
+    /// Classify why a file without an ID was skipped.
+    fn skip_reason(&self, path: &Path) -> SkipReason {
+        if self.is_filtered_out(path) {
+            return SkipReason::Filtered;
+        }
+        if self.id_parser.is_some() {
+            return if self.has_extension(path) {
+                SkipReason::NoMatch
+            } else {
+                SkipReason::WrongExtension
+            };
+        }
+        if self.is_too_deep(path) {
+            return SkipReason::TooDeep;
+        }
+        if self.unvalidated_id_parts_for(path).is_some() {
+            return SkipReason::InvalidIdChars;
+        }
+        let path = self.normalized_path(path);
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy())
+            .unwrap_or_default();
+        let Some(stem) = self
+            .extension_of(&path)
+            .and_then(|extension| filename.strip_suffix(extension))
+        else {
+            return SkipReason::WrongExtension;
+        };
+        if stem.is_empty() {
+            SkipReason::EmptyId
+        } else if stem.starts_with('.') && self.skip_prefixes.contains(&'.') {
+            SkipReason::Hidden
+        } else if stem.starts_with(self.skip_prefixes) {
+            SkipReason::Disabled
+        } else {
+            SkipReason::NoMatch
+        }
+    }
+
+    /// Report a skipped file to the skip callback, if set.
+    fn skip(&self, path: &Path, reason: SkipReason) {
+        if reason == SkipReason::InvalidIdChars {
+            warn!(
+                "[{}] Rejected '{}': ID contains disallowed characters",
+                type_name::<A>(),
+                path.display()
+            );
+        } else {
+            debug!(
+                "[{}] Skipping '{}': {reason:?}",
+                type_name::<A>(),
+                path.display()
+            );
+        }
+        if let Some(on_skip) = self.on_skip {
+            on_skip(path, reason);
+        }
+    }
+
+    /// Derive the ID and locale for a file, or the reason it is skipped.
+    fn classify(&self, path: &Path) -> Result<(Id, Option<String>), SkipReason> {
+        self.id_for(path).ok_or_else(|| self.skip_reason(path))
+    }
+
+    /// Derive the ID for a file, plus its locale in locale-variant mode.
+    fn id_for(&self, path: &Path) -> Option<(Id, Option<String>)> {
+        if self.is_filtered_out(path) {
+            return None;
+        }
+        if let Some(parser) = &self.id_parser {
+            if !self.has_extension(path) {
+                return None;
+            }
+            return parser(path).map(|id| (id, None));
+        }
+        let (id_str, locale) = self.id_parts_for(path)?;
+        let id = id_str.map_or_else(Id::default, |id| Id::from_filename(&id));
+        Some((id, locale))
+    }
+
+    /// Check if the include and exclude patterns rule a file out.
+    fn is_filtered_out(&self, path: &Path) -> bool {
+        if self.include_patterns.is_empty() && self.exclude_patterns.is_empty() {
+            return false;
+        }
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy())
+            .unwrap_or_default();
+        let matches = |pattern: &String| glob_matches(pattern, &filename);
+        (!self.include_patterns.is_empty() && !self.include_patterns.iter().any(matches))
+            || self.exclude_patterns.iter().any(matches)
+    }
+
+    /// Check if a file ends with one of the configured extensions.
+    fn has_extension(&self, path: &Path) -> bool {
+        self.extension_of(path).is_some()
+    }
+
+    /// Split a file's ID string from its locale.
+    ///
+    /// The ID string is `None` for an empty stem admitted by `allow_empty_id`,
+    /// which maps to `Id::default()`.
+    fn id_parts_for<'a>(&self, path: &'a Path) -> Option<(Option<Cow<'a, str>>, Option<String>)> {
+        let (mut id_str, locale) = self.unvalidated_id_parts_for(path)?;
+        if let (Some(delimiter), Some(full)) = (self.subtype_delimiter, &mut id_str)
+            && let Some(index) = full.find(delimiter)
+        {
+            if index == 0 {
+                return None;
+            }
+            *full = cow_slice(std::mem::take(full), 0..index);
+        }
+        if let (Some(validate), Some(id_str)) = (self.validate_id, &id_str)
+            && !validate(id_str)
+        {
+            return None;
+        }
+        Some((id_str, locale))
+    }
+
+    /// Split a file's ID string from its locale, without the charset check.
+    fn unvalidated_id_parts_for<'a>(
+        &self,
+        path: &'a Path,
+    ) -> Option<(Option<Cow<'a, str>>, Option<String>)> {
+        let Some(id_str) = self.id_string_for(path) else {
+            let is_empty_stem = self.normalized_path(path).file_name().is_some_and(|name| {
+                let name = name.to_string_lossy();
+                self.file_extensions
+                    .iter()
+                    .any(|extension| name == *extension)
+            });
+            return (self.allow_empty_id && is_empty_stem)
+                .then(|| (None, self.locales.map(|_| String::new())));
+        };
+        if self.locales.is_none() {
+            return Some((Some(id_str), None));
+        }
+        let (id_len, locale) = match id_str.rsplit_once('.') {
+            Some((id, locale)) if !id.is_empty() => (id.len(), locale.to_string()),
+            _ => (id_str.len(), String::new()),
+        };
+        Some((Some(cow_slice(id_str, 0..id_len)), Some(locale)))
+    }
+
+    /// Subtype tag of a file, if a subtype delimiter is set and present.
+    fn subtype_for(&self, path: &Path) -> Option<String> {
+        if self.id_parser.is_some() {
+            return None;
+        }
+        let delimiter = self.subtype_delimiter?;
+        let (Some(id_str), _) = self.unvalidated_id_parts_for(path)? else {
+            return None;
+        };
+        let (_, subtype) = id_str.split_once(delimiter)?;
+        (!subtype.is_empty()).then(|| subtype.to_string())
+    }
+
+    /// Run the validator on an asset, recording a rejection.
+    ///
+    /// Returns whether the asset may be registered.
+    fn validate(&self, folder_handle: &mut AssetFolderHandle<A>, path: &Path, asset: &A) -> bool {
+        let Some(Err(reason)) = self.validator.as_ref().map(|validator| validator(asset)) else {
+            return true;
+        };
+        warn!(
+            "[{}] Rejected '{}': {reason}",
+            type_name::<A>(),
+            path.display()
+        );
+        folder_handle.mark_progress();
+        folder_handle
+            .invalid
+            .push((path.display().to_string(), reason));
+        false
+    }
+
+    /// Stable numeric key for a file's ID, if numeric keys are enabled.
+    fn numeric_key_for(&self, path: &Path) -> Option<u64> {
+        if !self.numeric_keys || self.id_parser.is_some() {
+            return None;
+        }
+        let (id_str, _) = self.id_parts_for(path)?;
+        Some(stable_id_hash(id_str.as_deref().unwrap_or_default()))
+    }
+}
+
+/// Configuration resource for lazily loaded previews.
+#[derive(Resource)]
+struct PreviewConfig<Id, A, P> {
+    file_extensions: Vec<&'static str>,
+    preview_extension: &'static str,
+    _marker: PhantomData<(Id, A, P)>,
+}
+
+impl<Id, A, P> PreviewConfig<Id, A, P> {
+    /// The preview file next to an asset file.
+    fn preview_path(&self, path: &Path) -> Option<PathBuf> {
+        let file_name = path.file_name()?.to_string_lossy();
+        let stem = self
+            .file_extensions
+            .iter()
+            .find_map(|extension| file_name.strip_suffix(extension))?;
+        Some(path.with_file_name(format!("{stem}{}", self.preview_extension)))
+    }
+}
+
+/// Adds a JSON loader for `A`, see [`FolderLoaderPlugin::json`].
+#[cfg(feature = "json")]
+fn register_json_loader<A>(app: &mut App, file_extensions: &[&'static str])
+where
+    A: Asset + for<'de> serde::Deserialize<'de>,
+{
+    use bevy_common_assets::json::JsonAssetPlugin;
+
+    // Bevy matches loader extensions without the leading dot
+    let extensions: Vec<_> = file_extensions
+        .iter()
+        .map(|extension| extension.trim_start_matches('.'))
+        .collect();
+    if app.is_plugin_added::<JsonAssetPlugin<A>>() {
+        // The plugin can't be added twice, so its extensions are final
+        let registered = app.world().get_resource::<JsonExtensions<A>>();
+        let missing: Vec<_> = extensions
+            .iter()
+            .filter(|extension| {
+                !registered.is_some_and(|registered| registered.0.contains(extension))
+            })
+            .collect();
+        if !missing.is_empty() {
+            warn!(
+                "[{}] A JsonAssetPlugin is already added for this asset type; \
+                 files with the extensions {missing:?} won't load unless it covers them",
+                type_name::<A>()
+            );
+        }
+        return;
+    }
+    app.add_plugins(JsonAssetPlugin::<A>::new(&extensions));
+    app.insert_resource(JsonExtensions::<A>(extensions, PhantomData));
+}
+
+/// Extensions of the `JsonAssetPlugin` added by [`FolderLoaderPlugin::json`].
+#[cfg(feature = "json")]
+#[derive(Resource)]
+struct JsonExtensions<A>(Vec<&'static str>, PhantomData<fn() -> A>);
+
+/// Registers the reflected types of a folder loader and mirrors its IDs.
+fn register_reflection<Id, A>(app: &mut App)
+where
+    Id: Clone + Eq + Hash + Send + Sync + FromReflect + Typed + GetTypeRegistration + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
+{
+    app.register_type::<AssetFolder<Id, A>>();
+    app.register_type::<AssetFolderHandle<A>>();
+    app.register_type::<AssetRef<Id, A>>();
+    app.add_systems(Last, mirror_reflected_ids::<Id, A>);
+}
+
+/// Copies the registered IDs into the reflected `ids` list when they change.
+fn mirror_reflected_ids<Id, A>(mut library: ResMut<AssetFolder<Id, A>>)
+where
+    Id: Clone + Eq + Hash + Send + Sync + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
+{
+    if !library.is_changed() {
+        return;
+    }
+    let ids = library.iter_sorted().map(|(id, _)| id).collect();
+    library.bypass_change_detection().ids = ids;
+}
+
+/// Sets up the preview library and loading system for preview type `P`.
+fn add_preview_support<Id, A, P>(
+    app: &mut App,
+    schedule: InternedScheduleLabel,
+    file_extensions: Vec<&'static str>,
+    preview_extension: &'static str,
+) where
+    Id: Clone + Eq + Hash + Send + Sync + Default + FromFilename + std::fmt::Debug + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
+    P: Asset + Clone,
+{
+    app.insert_resource(PreviewConfig::<Id, A, P> {
+        file_extensions,
+        preview_extension,
+        _marker: PhantomData,
+    });
+    app.init_asset::<P>();
+    app.init_resource::<AssetFolder<Id, P>>();
+    app.add_systems(
+        schedule,
+        load_requested_previews::<Id, A, P>.after(load_assets_from_folder::<Id, A>),
+    );
+}
+
+// =============================================================================
+// FolderLoaderPluginGroup
+// =============================================================================
+
+/// Plugin group bundling folder loaders for several asset types.
+///
+/// Accumulates [`FolderLoaderPlugin`]s and adds them in one call. Each asset
+/// type can only be loaded by one plugin; adding a second plugin for the same
+/// `(Id, A)` pair replaces the first.
+///
+/// # Example
+///
+/// ```rust
+/// # use msg_load_folder::prelude::*;
+/// # use bevy::prelude::*;
+/// # use serde::Deserialize;
+/// # #[derive(Asset, Clone, Reflect, Deserialize)]
+/// # struct Spell { name: String }
+/// # #[derive(Asset, Clone, Reflect, Deserialize)]
+/// # struct Perk { name: String }
+/// # #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+/// # struct ContentId(u64);
+/// # impl From<String> for ContentId { fn from(s: String) -> Self { ContentId(s.len() as u64) } }
+/// # fn example(app: &mut App) {
+/// app.add_plugins(
+///     FolderLoaderPluginGroup::new()
+///         .add_folder::<ContentId, Spell>("prefabs/spells", ".spell.ron")
+///         .with(FolderLoaderPlugin::<ContentId, Perk>::new("prefabs/perks", ".perk.ron").with_priority(1)),
+/// );
+/// # }
+/// ```
+pub struct FolderLoaderPluginGroup {
+    builder: bevy::app::PluginGroupBuilder,
+}
+
+impl Default for FolderLoaderPluginGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FolderLoaderPluginGroup {
+    /// Create an empty plugin group.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            builder: bevy::app::PluginGroupBuilder::start::<Self>(),
+        }
+    }
+
+    /// Add a configured folder loader.
+    #[must_use]
+    pub fn with<Id, A>(mut self, plugin: FolderLoaderPlugin<Id, A>) -> Self
+    where
+        Id: Clone + Eq + Hash + Send + Sync + Default + FromFilename + std::fmt::Debug + 'static,
+        A: Asset + Clone + Send + Sync + 'static,
+    {
+        self.builder = self.builder.add(plugin);
+        self
+    }
+
+    /// Add a folder loader with default settings.
+    #[must_use]
+    pub fn add_folder<Id, A>(self, folder_path: &'static str, file_extension: &'static str) -> Self
+    where
+        Id: Clone + Eq + Hash + Send + Sync + Default + FromFilename + std::fmt::Debug + 'static,
+        A: Asset + Clone + Send + Sync + 'static,
+    {
+        self.with(FolderLoaderPlugin::<Id, A>::new(
+            folder_path,
+            file_extension,
+        ))
+    }
+}
+
+impl PluginGroup for FolderLoaderPluginGroup {
+    fn build(self) -> bevy::app::PluginGroupBuilder {
+        self.builder
+    }
+}
+
+// =============================================================================
+// FolderLoadPriorities Resource
+// =============================================================================
+
+/// Shared resource coordinating load order across folder loader plugins.
+///
+/// Every `FolderLoaderPlugin` registers its asset type and priority here.
+/// A folder only starts loading once all folders with a strictly higher
+/// priority are processed, so essential content (e.g. UI icons) can be
+/// loaded before optional content (e.g. music).
+#[derive(Resource, Default, Debug)]
+pub struct FolderLoadPriorities {
+    /// Registered folders keyed by asset type: (priority, processed).
+    folders: HashMap<TypeId, (i32, bool)>,
+}
+
+impl FolderLoadPriorities {
+    /// Register the folder for asset type `A` with the given priority.
+    pub fn register<A: 'static>(&mut self, priority: i32) {
+        self.folders.insert(TypeId::of::<A>(), (priority, false));
+    }
+
+    /// Mark the folder for asset type `A` as processed (or not).
+    pub fn set_processed<A: 'static>(&mut self, processed: bool) {
+        if let Some((_, done)) = self.folders.get_mut(&TypeId::of::<A>()) {
+            *done = processed;
+        }
+    }
+
+    /// Check if any folder with a priority higher than `priority` is still loading.
+    #[must_use]
+    pub fn is_blocked(&self, priority: i32) -> bool {
+        self.folders
+            .values()
+            .any(|(other, done)| *other > priority && !done)
+    }
+}
+
+// =============================================================================
+// FolderLoadProgress Resource
+// =============================================================================
+
+/// Shared resource aggregating load progress across folder loader plugins.
+///
+/// Every `FolderLoaderPlugin` registers its asset type here and updates its
+/// counts each frame, so a single progress bar can cover all folders.
+#[derive(Resource, Default, Debug)]
+pub struct FolderLoadProgress {
+    /// Registered folders in registration order.
+    folders: Vec<FolderProgress>,
+}
+
+/// Progress of a single folder in [`FolderLoadProgress`].
+#[derive(Debug)]
+struct FolderProgress {
+    type_id: TypeId,
+    type_name: &'static str,
+    discovered: usize,
+    loaded: usize,
+    processed: bool,
+}
+
+impl FolderProgress {
+    /// Loaded fraction, `1.0` once processed.
+    fn fraction(&self) -> f32 {
+        if self.processed {
+            1.0
+        } else if self.discovered == 0 {
+            0.0
+        } else {
+            self.loaded as f32 / self.discovered as f32
+        }
+    }
+}
+
+impl FolderLoadProgress {
+    /// Register the folder for asset type `A`.
+    pub fn register<A: 'static>(&mut self) {
+        if self.folder_mut::<A>().is_none() {
+            self.folders.push(FolderProgress {
+                type_id: TypeId::of::<A>(),
+                type_name: type_name::<A>(),
+                discovered: 0,
+                loaded: 0,
+                processed: false,
+            });
+        }
+    }
+
+    /// Update the counts of the folder for asset type `A`.
+    pub fn set<A: 'static>(&mut self, discovered: usize, loaded: usize, processed: bool) {
+        if let Some(folder) = self.folder_mut::<A>() {
+            folder.discovered = discovered;
+            folder.loaded = loaded;
+            folder.processed = processed;
+        }
+    }
+
+    /// Overall progress from `0.0` to `1.0`, the mean over all folders.
+    ///
+    /// A folder counts as `0.0` until its files are discovered and as `1.0`
+    /// once processed. Returns `1.0` if no folder is registered.
+    #[must_use]
+    pub fn fraction(&self) -> f32 {
+        if self.folders.is_empty() {
+            return 1.0;
+        }
+        let sum: f32 = self.folders.iter().map(FolderProgress::fraction).sum();
+        sum / self.folders.len() as f32
+    }
+
+    /// Progress of each folder by asset type name, in registration order.
+    pub fn per_type(&self) -> impl Iterator<Item = (&'static str, f32)> + '_ {
+        self.folders
+            .iter()
+            .map(|folder| (folder.type_name, folder.fraction()))
+    }
+
+    fn folder_mut<A: 'static>(&mut self) -> Option<&mut FolderProgress> {
+        self.folders
+            .iter_mut()
+            .find(|folder| folder.type_id == TypeId::of::<A>())
+    }
+}
+
+/// Remaining bytes to download, shared between a remote asset source and
+/// the folder loaders reading from it.
+///
+/// A remote `AssetReader` (e.g. over HTTP) holds a clone, calls
+/// [`Self::add`] with the content length of the files it is about to fetch
+/// and [`Self::received`] as their bytes arrive. Pass it to
+/// [`FolderLoaderPlugin::with_pending_bytes`] to drive a network loading bar.
+#[derive(Clone, Default, Debug)]
+pub struct PendingBytes(Arc<AtomicU64>);
+
+impl PendingBytes {
+    /// Count `bytes` more as pending.
+    pub fn add(&self, bytes: u64) {
+        self.0.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    /// Count `bytes` as downloaded, never going below zero.
+    pub fn received(&self, bytes: u64) {
+        let _ = self
+            .0
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |pending| {
+                Some(pending.saturating_sub(bytes))
+            });
+    }
+
+    /// Bytes still pending.
+    #[must_use]
+    pub fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+// =============================================================================
+// AssetFolderHandle Resource
+// =============================================================================
+
+/// Resource tracking folder load state for an asset type.
+///
+/// Generic over a marker type `A` to allow multiple folder handles
+/// for different asset types (spells, perks, actors, etc.).
+#[derive(Resource, Reflect)]
+#[reflect(Resource)]
+pub struct AssetFolderHandle<A: Send + Sync + 'static> {
+    /// Handle to the loaded folder.
+    ///
+    /// With [`FolderLoaderPlugin::with_folders`] this is the first folder.
+    pub handle: Option<Handle<LoadedFolder>>,
+    /// Handles to the folders after the first, in load order.
+    #[reflect(ignore)]
+    extra_handles: Vec<Handle<LoadedFolder>>,
+    /// Whether the folder has been processed.
+    processed: bool,
+    /// Number of matching files discovered in the folder.
+    total: usize,
+    /// Number of matching files whose data has loaded.
+    loaded: usize,
+    /// Number of discovered files still waiting for their data.
+    pending: usize,
+    /// Whether the folder contents have been discovered.
+    discovered: bool,
+    /// Debug-formatted IDs of registered assets.
+    loaded_ids: Vec<String>,
+    /// Paths that failed to load, with the reason.
+    failures: Vec<(String, String)>,
+    /// Paths whose asset the validator rejected, with its message.
+    invalid: Vec<(String, String)>,
+    /// Paths in the folder that didn't produce an ID, with the reason.
+    skipped_paths: Vec<(PathBuf, SkipReason)>,
+    /// Duplicate IDs as (id, first path, second path).
+    duplicates: Vec<(String, String, String)>,
+    /// Whether a rescan of the folder has been requested.
+    reload_requested: bool,
+    /// Latest modification time seen in the folder when loading started.
+    #[reflect(ignore)]
+    last_modified: Option<SystemTime>,
+    /// Assets referenced by registered assets, kept alive while loaded.
+    #[reflect(ignore)]
+    dependencies: Vec<Handle<LoadedUntypedAsset>>,
+    /// Time of the latest frame the loading system ran.
+    now: Duration,
+    /// Time the counts last changed (or loading started).
+    last_progress: Duration,
+    /// Time loading started.
+    started_at: Option<Duration>,
+    /// Time from the start of loading until the folder was processed.
+    load_duration: Option<Duration>,
+    /// Paths to re-attempt on the next run, set by [`Self::take_problems`].
+    retry: Vec<String>,
+    /// Failed paths holding another asset type, which a retry can't fix.
+    type_mismatches: Vec<String>,
+    /// Failed paths whose own asset the asset server failed to load.
+    failed_assets: Vec<String>,
+    /// Failed paths listed in the manifest that don't exist, with their ID.
+    missing_listed: Vec<(String, String)>,
+    /// Folder listing in progress during two-pass discovery, or the manifest
+    /// being read.
+    #[reflect(ignore)]
+    listing: Option<Task<ListedFiles>>,
+    /// ID extraction in progress with
+    /// [`FolderLoaderPlugin::async_id_extraction`], yielding the classified
+    /// files of the folder. Type-erased, as the handle has no ID type.
+    #[reflect(ignore)]
+    id_extraction: Option<Task<Box<dyn Any + Send>>>,
+    /// Failed path of the folder, listing or manifest with its typed error,
+    /// reported by [`Self::errors`] in place of the plain failure.
+    #[reflect(ignore)]
+    folder_error: Option<(String, FolderLoadError)>,
+    /// Whether the folder was unloaded with [`UnloadFolder`].
+    unloaded: bool,
+    /// Remaining bytes to download, mirrored from [`PendingBytes`].
+    pending_bytes: Option<u64>,
+    #[reflect(ignore)]
+    _marker: PhantomData<A>,
+}
+
+/// Stage of a folder load, as reported by [`AssetFolderHandle::phase`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    /// Loading hasn't started, e.g. while waiting on higher priorities.
+    Idle,
+    /// The folder is being resolved; the number of matching files is unknown.
+    Discovering,
+    /// Matching files are known and their data is streaming in.
+    Loading,
+    /// The folder has been processed.
+    Done,
+}
+
+impl<A: Send + Sync + 'static> Default for AssetFolderHandle<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Send + Sync + 'static> AssetFolderHandle<A> {
+    /// Create a new folder handle.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            handle: None,
+            extra_handles: Vec::new(),
+            processed: false,
+            total: 0,
+            loaded: 0,
+            pending: 0,
+            discovered: false,
+            loaded_ids: Vec::new(),
+            failures: Vec::new(),
+            invalid: Vec::new(),
+            skipped_paths: Vec::new(),
+            duplicates: Vec::new(),
+            reload_requested: false,
+            last_modified: None,
+            dependencies: Vec::new(),
+            now: Duration::ZERO,
+            last_progress: Duration::ZERO,
+            started_at: None,
+            load_duration: None,
+            retry: Vec::new(),
+            type_mismatches: Vec::new(),
+            failed_assets: Vec::new(),
+            missing_listed: Vec::new(),
+            listing: None,
+            id_extraction: None,
+            folder_error: None,
+            unloaded: false,
+            pending_bytes: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Request a rescan of the folder.
+    ///
+    /// On its next run the loading system resets this handle (dropping the
+    /// old `LoadedFolder` handle, counts, failures and skips), clears the
+    /// library and loads the folder again from scratch on the following
+    /// frame, so Bevy re-reads the directory. Files removed from disk vanish
+    /// from the library and new files appear. With
+    /// [`FolderLoaderPlugin::reload_only_if_modified`] the rescan is skipped
+    /// if nothing in the folder changed since the last load.
+    pub fn request_reload(&mut self) {
+        self.reload_requested = true;
+    }
+
+    /// Check if the folder was unloaded and hasn't been reloaded since.
+    #[must_use]
+    pub fn is_unloaded(&self) -> bool {
+        self.unloaded
+    }
+
+    /// Check if a rescan has been requested but not started yet.
+    #[must_use]
+    pub fn is_reload_requested(&self) -> bool {
+        self.reload_requested
+    }
+
+    /// The completion message for the current load.
+    fn completed(&self, folder_path: &'static str) -> FolderLoadCompleted<A>
+    where
+        A: Asset,
+    {
+        let failed = self
+            .failures
+            .iter()
+            .chain(&self.invalid)
+            .map(|(path, _)| path.clone())
+            .collect();
+        FolderLoadCompleted::new(folder_path, self.loaded_ids.len(), failed)
+    }
+
+    /// Reset all load state, keeping the last seen modification time.
+    fn reset(&mut self) {
+        *self = Self {
+            last_modified: self.last_modified,
+            ..Self::new()
+        };
+    }
+
+    /// Check if the folder has been processed.
+    #[must_use]
+    pub fn is_loaded(&self) -> bool {
+        self.processed
+    }
+
+    /// Estimated number of bytes still to be downloaded for this folder.
+    ///
+    /// Only available while loading from a remote source that reports
+    /// content lengths, see [`FolderLoaderPlugin::with_pending_bytes`].
+    /// Always `None` for local sources and once the folder is processed.
+    #[must_use]
+    pub fn pending_bytes(&self) -> Option<u64> {
+        self.pending_bytes
+    }
+
+    /// The current stage of the folder load.
+    #[must_use]
+    pub fn phase(&self) -> Phase {
+        if self.processed {
+            Phase::Done
+        } else if self.discovered {
+            Phase::Loading
+        } else if self.is_started() {
+            Phase::Discovering
+        } else {
+            Phase::Idle
+        }
+    }
+
+    /// Number of matching files discovered in the folder.
+    ///
+    /// Zero until discovery finishes; see [`FolderLoaderPlugin::two_pass`]
+    /// for knowing it before any asset loads.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Number of assets registered so far, e.g. for "42/60 spells loaded".
+    ///
+    /// Only grows during a load and is reset to zero when the folder
+    /// reloads. Substitutes from [`FolderLoaderPlugin::on_failure`] count as
+    /// loaded.
+    #[must_use]
+    pub fn loaded(&self) -> usize {
+        self.loaded
+    }
+
+    /// Number of discovered files still waiting for their data.
+    ///
+    /// Files that failed or were rejected are neither loaded nor pending.
+    /// Zero before discovery and once the folder has been processed.
+    #[must_use]
+    pub fn pending(&self) -> usize {
+        self.pending
+    }
+
+    /// Check if loading made no progress for at least `threshold`.
+    ///
+    /// Progress means discovering the folder or a change in the loaded or
+    /// failed counts, measured with Bevy's `Time`. Always `false` before
+    /// loading starts and once the folder has been processed, so a loading
+    /// screen can show "taking longer than expected" while this is `true`.
+    /// Apps without a `Time` resource (no `TimePlugin`) still load, but this
+    /// then never becomes `true`.
+    #[must_use]
+    pub fn is_stalled(&self, threshold: Duration) -> bool {
+        self.is_started()
+            && !self.processed
+            && self.now.saturating_sub(self.last_progress) >= threshold
+    }
+
+    /// Time the folder took from the start of loading until it was processed.
+    ///
+    /// Measured with Bevy's `Time`, so it has frame granularity, and zero
+    /// without a `Time` resource. `None` until the folder has been processed;
+    /// a reload measures anew.
+    #[must_use]
+    pub fn load_duration(&self) -> Option<Duration> {
+        self.load_duration
+    }
+
+    /// Mark the folder processed, recording the load duration.
+    fn mark_processed(&mut self) {
+        self.processed = true;
+        self.pending_bytes = None;
+        self.load_duration = self
+            .started_at
+            .map(|started_at| self.now.saturating_sub(started_at));
+    }
+
+    /// Record the time of the current frame.
+    fn tick(&mut self, now: Duration) {
+        self.now = now;
+    }
+
+    /// Record that loading made progress this frame.
+    fn mark_progress(&mut self) {
+        self.last_progress = self.now;
+    }
+
+    /// Check if the folder load has been started.
+    fn is_started(&self) -> bool {
+        self.handle.is_some() || self.listing.is_some() || self.discovered
+    }
+
+    /// Drain the failed and skipped paths and re-attempt the failed ones.
+    ///
+    /// Returns `(failed, skipped)` and clears both lists. On its next run the
+    /// loading system loads each failed or rejected path again, registering
+    /// the ones that now succeed and recording the rest as before, without
+    /// touching the assets that already loaded. Useful after fixing broken
+    /// content, as a lighter alternative to [`Self::request_reload`].
+    ///
+    /// Skipped paths are only returned: they were skipped for their name,
+    /// which a retry doesn't change. Files holding another asset type stay
+    /// failed and are not returned, for the same reason.
+    pub fn take_problems(&mut self) -> (Vec<String>, Vec<String>) {
+        let (mismatched, failures): (Vec<_>, Vec<_>) = self
+            .failures
+            .drain(..)
+            .partition(|(path, _)| self.type_mismatches.contains(path));
+        self.failures = mismatched;
+        self.failed_assets.clear();
+        self.missing_listed.clear();
+        let failed: Vec<String> = failures
+            .into_iter()
+            .chain(self.invalid.drain(..))
+            .map(|(path, _)| path)
+            .collect();
+        self.folder_error = None;
+        let skipped: Vec<String> = self
+            .skipped_paths
+            .drain(..)
+            .map(|(path, _)| path.display().to_string())
+            .collect();
+        self.retry.extend(failed.iter().cloned());
+        if !self.retry.is_empty() {
+            self.processed = false;
+        }
+        (failed, skipped)
+    }
+
+    /// Paths in the folder that didn't produce an ID, with the reason.
+    ///
+    /// Filled as discovery runs, in discovery order, so content linting can
+    /// report e.g. how many files are disabled or have an empty name. Files
+    /// beyond [`FolderLoaderPlugin::limit`] are listed with
+    /// [`SkipReason::OverLimit`].
+    #[must_use]
+    pub fn skipped(&self) -> &[(PathBuf, SkipReason)] {
+        &self.skipped_paths
+    }
+
+    /// Why `path` was skipped, or `None` if it wasn't.
+    ///
+    /// `path` is the asset path, e.g. `"prefabs/spells/_draft.spell.ron"`.
+    #[must_use]
+    pub fn skip_reason(&self, path: impl AsRef<Path>) -> Option<SkipReason> {
+        let path = path.as_ref();
+        self.skipped_paths
+            .iter()
+            .find(|(skipped, _)| skipped == path)
+            .map(|(_, reason)| *reason)
+    }
+
+    /// Build a report of the load outcome so far.
+    ///
+    /// With the `serde` feature the report is serializable, so CI jobs can
+    /// dump and assert on it.
+    #[must_use]
+    pub fn report(&self) -> LoadReport {
+        LoadReport {
+            loaded: self.loaded_ids.clone(),
+            failed: self.failures.iter().chain(&self.invalid).cloned().collect(),
+            skipped: self
+                .skipped_paths
+                .iter()
+                .map(|(path, _)| path.display().to_string())
+                .collect(),
+            duplicates: self.duplicates.clone(),
+        }
+    }
+
+    /// The actionable problems of the load so far, in the order they occurred
+    /// within each kind: failed loads, then assets rejected by the validator,
+    /// then rejected IDs, then duplicates.
+    ///
+    /// Files skipped by convention (other extensions, hidden or disabled
+    /// files) are not errors; see [`Self::report`] for those.
+    #[must_use]
+    pub fn errors(&self) -> Vec<FolderLoadError> {
+        let failed = self.failures.iter().map(|(path, reason)| {
+            if let Some((error_path, error)) = &self.folder_error
+                && error_path == path
+            {
+                error.clone()
+            } else if let Some((_, id)) = self
+                .missing_listed
+                .iter()
+                .find(|(missing_path, _)| missing_path == path)
+            {
+                FolderLoadError::ManifestFileMissing {
+                    id: id.clone(),
+                    path: path.clone(),
+                }
+            } else if self.failed_assets.contains(path) {
+                FolderLoadError::AssetFailed {
+                    path: path.clone(),
+                    reason: reason.clone(),
+                }
+            } else {
+                FolderLoadError::ParseFailed {
+                    path: path.clone(),
+                    reason: reason.clone(),
+                }
+            }
+        });
+        let invalid = self
+            .invalid
+            .iter()
+            .map(|(path, reason)| FolderLoadError::ValidationFailed {
+                path: path.clone(),
+                reason: reason.clone(),
+            });
+        let rejected = self
+            .skipped_paths
+            .iter()
+            .filter_map(|(path, reason)| match reason {
+                SkipReason::EmptyId => Some(FolderLoadError::EmptyId {
+                    path: path.display().to_string(),
+                }),
+                SkipReason::InvalidIdChars => Some(FolderLoadError::InvalidIdChars {
+                    path: path.display().to_string(),
+                }),
+                _ => None,
+            });
+        let duplicates = self.duplicates.iter().map(|(id, first_path, second_path)| {
+            FolderLoadError::DuplicateId {
+                id: id.clone(),
+                first_path: first_path.clone(),
+                second_path: second_path.clone(),
+            }
+        });
+        failed
+            .chain(invalid)
+            .chain(rejected)
+            .chain(duplicates)
+            .collect()
+    }
+
+    /// Total number of handles in the resolved folder, including files that
+    /// didn't match the extension filter.
+    ///
+    /// Compare against the library length to spot filtering issues. Returns
+    /// `None` until the folder has been resolved.
+    #[must_use]
+    pub fn raw_handle_count(&self, loaded_folders: &Assets<LoadedFolder>) -> Option<usize> {
+        let handle = self.handle.as_ref()?;
+        std::iter::once(handle)
+            .chain(&self.extra_handles)
+            .map(|handle| {
+                loaded_folders
+                    .get(handle)
+                    .map(|folder| folder.handles.len())
+            })
+            .sum()
+    }
+}
+
+impl<A> AssetFolderHandle<A>
+where
+    A: Asset + Clone + Send + Sync + 'static,
+{
+    /// List every entry of the folder: loaded IDs, failed and skipped paths.
+    ///
+    /// Loaded entries come from `library` (including disabled IDs), followed
+    /// by failures and skips in discovery order.
+    #[must_use]
+    pub fn all_entries<Id>(&self, library: &AssetFolder<Id, A>) -> Vec<EntryStatus<Id>>
+    where
+        Id: Clone + Eq + Hash + Send + Sync + 'static,
+    {
+        let loaded = library.assets.keys().cloned().map(EntryStatus::Loaded);
+        let failed = self
+            .failures
+            .iter()
+            .map(|(path, reason)| EntryStatus::Failed(path.clone(), reason.clone()));
+        let skipped = self
+            .skipped_paths
+            .iter()
+            .map(|(path, reason)| EntryStatus::Skipped(path.display().to_string(), *reason));
+        loaded.chain(failed).chain(skipped).collect()
+    }
+}
+
+/// Status of a single folder entry, as listed by
+/// [`AssetFolderHandle::all_entries`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntryStatus<Id> {
+    /// An asset registered under this ID.
+    Loaded(Id),
+    /// A path that failed to load, with the reason.
+    Failed(String, String),
+    /// A path that was skipped, with the reason.
+    Skipped(String, SkipReason),
+}
+
+/// A problem with a folder entry, as listed by [`AssetFolderHandle::errors`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FolderLoadError {
+    /// The folder failed to load as a whole, or a file it needs (folder
+    /// defaults, a dependency, a file holding another asset type) did.
+    ParseFailed {
+        /// Path of the file, or of the folder if it failed as a whole.
+        path: String,
+        /// The asset server's error message.
+        reason: String,
+    },
+    /// A matching file's asset failed to load, e.g. malformed content.
+    AssetFailed {
+        /// Path of the file.
+        path: String,
+        /// The asset server's error, including the loader's own message
+        /// (e.g. the RON parse error).
+        reason: String,
+    },
+    /// The asset was rejected by the configured validator.
+    ValidationFailed {
+        /// Path of the file.
+        path: String,
+        /// The validator's message.
+        reason: String,
+    },
+    /// The filename consists of only the extension.
+    EmptyId {
+        /// Path of the file.
+        path: String,
+    },
+    /// The ID failed the configured charset check.
+    InvalidIdChars {
+        /// Path of the file.
+        path: String,
+    },
+    /// The asset source to list the folder or read the manifest from isn't
+    /// registered.
+    SourceUnavailable {
+        /// Path of the folder or manifest.
+        path: String,
+        /// The asset server's error message.
+        reason: String,
+    },
+    /// The manifest couldn't be parsed, see
+    /// [`FolderLoaderPlugin::with_manifest`].
+    InvalidManifest {
+        /// Path of the manifest.
+        path: String,
+        /// The RON parse error.
+        reason: String,
+    },
+    /// A file listed in the manifest doesn't exist.
+    ManifestFileMissing {
+        /// ID the manifest lists the file under.
+        id: String,
+        /// Path of the file.
+        path: String,
+    },
+    /// The configured folder doesn't exist on the asset source.
+    FolderNotFound {
+        /// Path of the folder.
+        path: String,
+    },
+    /// The folder didn't finish loading in time, see [`load_folder_blocking`].
+    TimedOut {
+        /// Path of the folder.
+        path: String,
+    },
+    /// Two files produced the same ID, resolved by the [`DuplicatePolicy`].
+    DuplicateId {
+        /// Debug-formatted ID.
+        id: String,
+        /// Path of the file seen first.
+        first_path: String,
+        /// Path of the file seen later.
+        second_path: String,
+    },
+}
+
+impl std::fmt::Display for FolderLoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ParseFailed { path, reason } | Self::AssetFailed { path, reason } => {
+                write!(f, "failed to load '{path}': {reason}")
+            }
+            Self::ValidationFailed { path, reason } => write!(f, "'{path}' is invalid: {reason}"),
+            Self::EmptyId { path } => write!(f, "'{path}' has an empty ID"),
+            Self::SourceUnavailable { path, reason } => {
+                write!(f, "no asset source to read '{path}' from: {reason}")
+            }
+            Self::InvalidManifest { path, reason } => {
+                write!(f, "invalid manifest '{path}': {reason}")
+            }
+            Self::ManifestFileMissing { id, path } => {
+                write!(
+                    f,
+                    "'{path}' listed as '{id}' in the manifest does not exist"
+                )
+            }
+            Self::FolderNotFound { path } => write!(f, "folder '{path}' does not exist"),
+            Self::TimedOut { path } => write!(f, "timed out loading '{path}'"),
+            Self::InvalidIdChars { path } => {
+                write!(f, "'{path}' has an ID with disallowed characters")
+            }
+            Self::DuplicateId {
+                id,
+                first_path,
+                second_path,
+            } => write!(f, "duplicate ID {id} in '{first_path}' and '{second_path}'"),
+        }
+    }
+}
+
+impl std::error::Error for FolderLoadError {}
+
+/// Machine-checkable outcome of a folder load.
+///
+/// Created by [`AssetFolderHandle::report`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LoadReport {
+    /// Debug-formatted IDs of the registered assets.
+    pub loaded: Vec<String>,
+    /// Paths that failed to load, with the reason.
+    ///
+    /// The reason is the asset server's error, including the loader's own
+    /// message (e.g. the RON parse error of a malformed file).
+    pub failed: Vec<(String, String)>,
+    /// Paths in the folder that were skipped (hidden, disabled, wrong extension).
+    pub skipped: Vec<String>,
+    /// Duplicate IDs as (id, first path, second path).
+    pub duplicates: Vec<(String, String, String)>,
+}
+
+/// Why the loader skipped a file in the folder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum SkipReason {
+    /// The filename doesn't end with the configured extension.
+    WrongExtension,
+    /// The filename starts with `.`.
+    Hidden,
+    /// The filename starts with `_`.
+    Disabled,
+    /// The filename consists of only the extension.
+    EmptyId,
+    /// The ID pattern didn't match the filename.
+    NoMatch,
+    /// The ID failed the configured charset check.
+    InvalidIdChars,
+    /// The file fell beyond the configured load limit.
+    OverLimit,
+    /// The file is nested deeper than the recursive `max_depth`.
+    TooDeep,
+    /// The file name didn't pass the include or exclude patterns.
+    Filtered,
+}
+
+// =============================================================================
+// AssetFolder Resource
+// =============================================================================
+
+/// Generic library resource for assets loaded from folders.
+///
+/// Maps asset IDs to their handles, providing convenient access methods.
+/// This is the main resource created by `FolderLoaderPlugin`.
+///
+/// Derefs to the underlying `HashMap` for read access. Unlike the
+/// library's own methods, that map includes disabled IDs.
+///
+/// # Type Parameters
+///
+/// * `Id` - The ID type (e.g., SpellId, PerkId)
+/// * `A` - The asset type (e.g., Spell, PerkData)
+///
+/// # Example
+///
+/// ```rust
+/// # use msg_load_folder::prelude::*;
+/// # use bevy::prelude::*;
+/// # use serde::Deserialize;
+/// # #[derive(Asset, Clone, Reflect, Deserialize)]
+/// # struct Spell { name: String }
+/// # #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+/// # struct SpellId(u64);
+/// # impl From<String> for SpellId { fn from(s: String) -> Self { SpellId(s.len() as u64) } }
+/// fn my_system(
+///     library: Res<AssetFolder<SpellId, Spell>>,
+///     assets: Res<Assets<Spell>>,
+/// ) {
+///     let spell_id = SpellId::default();
+///     if let Some(spell) = library.get_data(spell_id, &assets) {
+///         info!("Found spell: {}", spell.name);
+///     }
+/// }
+/// ```
+#[derive(Resource, Clone, Reflect, Deref)]
+#[reflect(Resource)]
+pub struct AssetFolder<Id, A>
+where
+    Id: Clone + Eq + Hash + Send + Sync + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
+{
+    /// Asset handles indexed by ID.
+    #[deref]
+    #[reflect(ignore)]
+    assets: HashMap<Id, Handle<A>>,
+    /// IDs disabled at runtime, hidden from normal lookup and iteration.
+    #[reflect(ignore)]
+    disabled: HashSet<Id>,
+    /// Whether public mutation is guarded, see [`Self::freeze`].
+    frozen: bool,
+    /// Minimum number of assets before the library reports ready.
+    ready_at_count: usize,
+    /// Per-locale handles for each ID in locale-variant mode.
+    #[reflect(ignore)]
+    variants: HashMap<Id, HashMap<String, Handle<A>>>,
+    /// Locale used to resolve variants.
+    locale: String,
+    /// Locale used when a variant for `locale` is missing.
+    fallback_locale: String,
+    /// Library being filled by a staged reload, swapped in once complete.
+    #[reflect(ignore)]
+    staging: Option<Box<Self>>,
+    /// Source file of each registered ID.
+    #[reflect(ignore)]
+    source_paths: HashMap<Id, PathBuf>,
+    /// File extension each registered ID matched.
+    #[reflect(ignore)]
+    matched_extensions: HashMap<Id, &'static str>,
+    /// Subtype tag parsed from each registered ID's filename.
+    #[reflect(ignore)]
+    subtypes: HashMap<Id, String>,
+    /// IDs whose preview has been requested but not loaded yet.
+    #[reflect(ignore)]
+    preview_requests: Vec<Id>,
+    /// Ordering weight of an asset for [`Self::iter_by_sort_key`].
+    #[reflect(ignore)]
+    sort_key: Option<fn(&A) -> i64>,
+    /// Stable numeric keys by ID, when numeric keys are enabled.
+    #[reflect(ignore)]
+    numeric_keys: HashMap<Id, u64>,
+    /// IDs by stable numeric key.
+    #[reflect(ignore)]
+    ids_by_numeric_key: HashMap<u64, Id>,
+    /// IDs by the asset their handle points to, for [`Self::id_of`].
+    #[reflect(ignore)]
+    ids_by_asset: HashMap<AssetId<A>, Id>,
+    /// Whether handles may have changed behind the reverse map, through
+    /// [`Self::get_mut`], [`Self::iter_mut`] or [`Self::assets_mut`].
+    #[reflect(ignore)]
+    ids_by_asset_stale: bool,
+    /// Enabled IDs in source path order, mirrored for reflection by
+    /// [`FolderLoaderPlugin::with_reflection`].
+    ids: Vec<Id>,
+    /// Handle returned by [`Self::get_or_default`] for unknown IDs.
+    #[reflect(ignore)]
+    fallback: Handle<A>,
+    /// Folder the library was loaded from, empty if built manually.
+    folder_path: &'static str,
+    /// Primary file extension of the loader, empty if built manually.
+    file_extension: &'static str,
+}
+
+/// Shorter name for [`AssetFolder`], e.g. `Res<Library<SpellId, Spell>>`.
+pub type Library<Id, A> = AssetFolder<Id, A>;
+
+// Manual Default implementation that doesn't require A: Default
+impl<Id, A> Default for AssetFolder<Id, A>
+where
+    Id: Clone + Eq + Hash + Send + Sync + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Id, A> AssetFolder<Id, A>
+where
+    Id: Clone + Eq + Hash + Send + Sync + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
+{
+    /// Create a new empty library.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            assets: HashMap::new(),
+            disabled: HashSet::new(),
+            frozen: false,
+            ready_at_count: 1,
+            variants: HashMap::new(),
+            locale: String::new(),
+            fallback_locale: String::new(),
+            staging: None,
+            source_paths: HashMap::new(),
+            matched_extensions: HashMap::new(),
+            subtypes: HashMap::new(),
+            preview_requests: Vec::new(),
+            sort_key: None,
+            numeric_keys: HashMap::new(),
+            ids_by_numeric_key: HashMap::new(),
+            ids_by_asset: HashMap::new(),
+            ids_by_asset_stale: false,
+            ids: Vec::new(),
+            fallback: Handle::default(),
+            folder_path: "",
+            file_extension: "",
+        }
+    }
+
+    /// Get the folder this library was loaded from.
+    ///
+    /// Lets generic diagnostics tell libraries apart. Empty for libraries
+    /// not created by a [`FolderLoaderPlugin`].
+    #[must_use]
+    pub fn source_folder(&self) -> &str {
+        self.folder_path
+    }
+
+    /// Get the file extension the loader matches, e.g. `".spell.ron"`.
+    ///
+    /// With [`FolderLoaderPlugin::with_extensions`] this is the first one;
+    /// see [`Self::matched_extension`] for the extension of a single asset.
+    /// Empty for libraries not created by a [`FolderLoaderPlugin`].
+    #[must_use]
+    pub fn source_extension(&self) -> &str {
+        self.file_extension
+    }
+
+    /// Release spare capacity left over from incremental inserts.
+    ///
+    /// Worthwhile for libraries of thousands of entries once loading is done;
+    /// see [`FolderLoaderPlugin::compact_on_load`] to run it automatically.
+    pub fn compact(&mut self) {
+        self.assets.shrink_to_fit();
+        self.disabled.shrink_to_fit();
+        self.variants.shrink_to_fit();
+        self.source_paths.shrink_to_fit();
+        self.matched_extensions.shrink_to_fit();
+        self.subtypes.shrink_to_fit();
+        self.numeric_keys.shrink_to_fit();
+        self.ids_by_numeric_key.shrink_to_fit();
+        self.ids_by_asset.shrink_to_fit();
+    }
+
+    /// Get the library from a `World`, for tooling that doesn't run systems.
+    ///
+    /// Returns `None` if no [`FolderLoaderPlugin`] registered this library.
+    #[must_use]
+    pub fn from_world(world: &World) -> Option<&Self> {
+        world.get_resource::<Self>()
+    }
+
+    /// Build a library from a `LoadedFolder` the app loaded itself.
+    ///
+    /// For apps that call `AssetServer::load_folder` on their own and don't
+    /// want the plugin's loading systems; the plugin stays the default way
+    /// to get a library. IDs follow [`id_from_filename_with_extension`], and
+    /// files of other asset types or extensions are skipped. The library is
+    /// built in one go, so assets only referenced by the folder may not
+    /// have loaded yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FolderLoadError::DuplicateId`] if two files share an ID.
+    pub fn try_from_loaded_folder(
+        folder: &LoadedFolder,
+        file_extension: &str,
+    ) -> Result<Self, FolderLoadError>
+    where
+        Id: FromFilename + std::fmt::Debug,
+    {
+        let mut library = Self::new();
+        for handle in &folder.handles {
+            let (Some(path), Ok(handle)) = (handle.path(), handle.clone().try_typed::<A>()) else {
+                continue;
+            };
+            let path = path.path();
+            let Some(id) = id_from_filename_with_extension::<Id>(path, file_extension) else {
+                continue;
+            };
+            if let Some(first_path) = library.path_of(id.clone()) {
+                return Err(FolderLoadError::DuplicateId {
+                    id: format!("{id:?}"),
+                    first_path: first_path.display().to_string(),
+                    second_path: path.display().to_string(),
+                });
+            }
+            library.register(id, None, path, handle);
+        }
+        Ok(library)
+    }
+
+    /// Create an empty library with room for `capacity` IDs.
+    ///
+    /// Intended for benchmarks; the loader sizes its libraries itself.
+    #[cfg(feature = "test-util")]
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            assets: HashMap::with_capacity(capacity),
+            ..Self::new()
+        }
+    }
+
+    /// Get handle for an ID.
+    ///
+    /// Returns `None` for IDs disabled via [`Self::set_disabled`].
+    #[must_use]
+    pub fn get(&self, id: Id) -> Option<&Handle<A>> {
+        if self.disabled.contains(&id) {
+            return None;
+        }
+        self.assets.get(&id)
+    }
+
+    /// Get the handle for an ID, or the fallback handle if it's unknown.
+    ///
+    /// Also returns the fallback for disabled IDs. The fallback is set with
+    /// [`FolderLoaderPlugin::with_fallback`] or [`Self::set_fallback`];
+    /// without one this is `Handle::default()`.
+    #[must_use]
+    pub fn get_or_default(&self, id: Id) -> &Handle<A> {
+        self.get(id).unwrap_or(&self.fallback)
+    }
+
+    /// Get the fallback handle returned by [`Self::get_or_default`].
+    #[must_use]
+    pub fn fallback(&self) -> &Handle<A> {
+        &self.fallback
+    }
+
+    /// Set the fallback handle returned by [`Self::get_or_default`].
+    pub fn set_fallback(&mut self, handle: Handle<A>) {
+        self.fallback = handle;
+    }
+
+    /// Get handles for several IDs at once, in the order given.
+    ///
+    /// Each entry is what [`Self::get`] returns for that ID, so the result
+    /// can be destructured: `let [sword, shield] = library.get_many([a, b]);`
+    #[must_use]
+    pub fn get_many<const N: usize>(&self, ids: [Id; N]) -> [Option<&Handle<A>>; N] {
+        ids.map(|id| self.get(id))
+    }
+
+    /// Get the ID a handle is registered under, e.g. for a UI label of a
+    /// handle taken from a component.
+    ///
+    /// Handles are compared by the asset they point to. O(1) through a
+    /// reverse map kept up to date by the library's own methods. Once
+    /// handles were handed out mutably (through [`Self::get_mut`],
+    /// [`Self::iter_mut`] or [`Self::assets_mut`]) the map may be out of
+    /// date, so lookups fall back to an O(n) scan until the library is
+    /// cleared or reloaded. Returns `None` for disabled IDs. If several IDs
+    /// share an asset, any one of them is returned.
+    #[must_use]
+    pub fn id_of(&self, handle: &Handle<A>) -> Option<Id> {
+        let id = if self.ids_by_asset_stale {
+            self.assets
+                .iter()
+                .find(|(_, registered)| same_asset(registered, handle))
+                .map(|(id, _)| id)
+        } else {
+            self.ids_by_asset.get(&handle.id())
+        }?;
+        (!self.disabled.contains(id)).then(|| id.clone())
+    }
+
+    /// Get the asset data for an ID.
+    ///
+    /// Resolves the handle from [`Self::get`] in `assets`, returning `None`
+    /// if the ID is unknown or disabled or its data isn't loaded yet.
+    #[must_use]
+    pub fn get_data<'a>(&self, id: Id, assets: &'a Assets<A>) -> Option<&'a A> {
+        self.get(id).and_then(|handle| assets.get(handle))
+    }
+
+    /// Get mutable handle for an ID.
+    ///
+    /// Returns `None` for IDs disabled via [`Self::set_disabled`].
+    #[must_use]
+    pub fn get_mut(&mut self, id: Id) -> Option<&mut Handle<A>> {
+        if self.is_frozen_for("get_mut") || self.disabled.contains(&id) {
+            return None;
+        }
+        let handle = self.assets.get_mut(&id)?;
+        self.ids_by_asset_stale = true;
+        Some(handle)
+    }
+
+    /// Get handle for an ID, even if it is disabled.
+    #[must_use]
+    pub fn get_including_disabled(&self, id: Id) -> Option<&Handle<A>> {
+        self.assets.get(&id)
+    }
+
+    /// Disable or re-enable an ID at runtime.
+    ///
+    /// Disabled IDs stay registered but are hidden from `get`, `contains`,
+    /// `keys` and `iter`. This is independent of the `_` filename convention,
+    /// which prevents files from being registered at all.
+    pub fn set_disabled(&mut self, id: Id, disabled: bool) {
+        if disabled {
+            self.disabled.insert(id);
+        } else {
+            self.disabled.remove(&id);
+        }
+    }
+
+    /// Check if an ID is disabled at runtime.
+    #[must_use]
+    pub fn is_disabled(&self, id: Id) -> bool {
+        self.disabled.contains(&id)
+    }
+
+    /// Insert a handle for an ID.
+    ///
+    /// Refused on a frozen library, see [`Self::freeze`].
+    pub fn insert(&mut self, id: Id, handle: Handle<A>) -> Option<Handle<A>> {
+        if self.is_frozen_for("insert") {
+            return None;
+        }
+        self.put(id, handle)
+    }
+
+    /// Remove the handle for an ID.
+    ///
+    /// Also drops the ID's locale variants, source path, subtype and numeric
+    /// key. Refused on a frozen library, see [`Self::freeze`].
+    pub fn remove(&mut self, id: Id) -> Option<Handle<A>> {
+        if self.is_frozen_for("remove") {
+            return None;
+        }
+        let handle = self.take(&id);
+        self.forget(&id);
+        handle
+    }
+
+    /// Remove all handles, keeping the library settings and disabled IDs.
+    ///
+    /// Refused on a frozen library, see [`Self::freeze`].
+    pub fn clear(&mut self) {
+        if self.is_frozen_for("clear") {
+            return;
+        }
+        self.clear_loaded();
+    }
+
+    /// Mark the library as authoritative, guarding it against mutation.
+    ///
+    /// Afterwards [`Self::insert`], [`Self::remove`], [`Self::clear`],
+    /// [`Self::get_mut`] and [`Self::iter_mut`] panic in debug builds and log
+    /// a warning and do nothing in release builds. This catches gameplay code
+    /// mutating the content library. The loader itself (reloads, hot-added
+    /// files) is not affected, and neither is [`Self::assets_mut`].
+    pub fn freeze(&mut self) {
+        self.frozen = true;
+    }
+
+    /// Check if the library has been frozen with [`Self::freeze`].
+    #[must_use]
+    pub fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// Guards a mutating call on a frozen library.
+    fn is_frozen_for(&self, operation: &str) -> bool {
+        if self.frozen {
+            debug_assert!(
+                false,
+                "[{}] {operation} called on a frozen library",
+                type_name::<A>()
+            );
+            warn!(
+                "[{}] Ignoring {operation} on a frozen library",
+                type_name::<A>()
+            );
+        }
+        self.frozen
+    }
+
+    /// Check if the library contains an enabled ID.
+    #[must_use]
+    pub fn contains(&self, id: Id) -> bool {
+        self.assets.contains_key(&id) && !self.disabled.contains(&id)
+    }
+
+    /// Check if the library contains every one of `ids`, all enabled.
+    ///
+    /// See [`Self::missing`] to find out which are absent.
+    #[must_use]
+    pub fn contains_all(&self, ids: impl IntoIterator<Item = Id>) -> bool {
+        ids.into_iter().all(|id| self.contains(id))
+    }
+
+    /// The IDs among `ids` the library doesn't contain, in the order given.
+    ///
+    /// Disabled IDs count as missing. Useful to validate required content at
+    /// startup, e.g. panicking with the list of starter spells that didn't
+    /// load.
+    #[must_use]
+    pub fn missing(&self, ids: impl IntoIterator<Item = Id>) -> Vec<Id> {
+        ids.into_iter()
+            .filter(|id| !self.contains(id.clone()))
+            .collect()
+    }
+
+    /// Check if enough assets have been loaded.
+    ///
+    /// By default the library is ready as soon as it holds any enabled
+    /// asset; see [`Self::set_ready_at_count`] to require more.
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        self.len() >= self.ready_at_count
+    }
+
+    /// Set the minimum number of assets required before [`Self::is_ready`]
+    /// returns true. Values below `1` are treated as `1`.
+    pub fn set_ready_at_count(&mut self, count: usize) {
+        self.ready_at_count = count.max(1);
+    }
+
+    /// Insert a locale variant for an ID, re-resolving which variant is active.
+    pub fn insert_variant(&mut self, id: Id, locale: impl Into<String>, handle: Handle<A>) {
+        self.variants
+            .entry(id.clone())
+            .or_default()
+            .insert(locale.into(), handle);
+        self.select_variant(id);
+    }
+
+    /// Get the handle of a specific locale variant.
+    #[must_use]
+    pub fn variant(&self, id: Id, locale: &str) -> Option<&Handle<A>> {
+        self.variants.get(&id)?.get(locale)
+    }
+
+    /// The locale used to resolve variants.
+    #[must_use]
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Switch the active locale, re-resolving every ID with variants.
+    pub fn set_locale(&mut self, locale: impl Into<String>) {
+        self.locale = locale.into();
+        let ids: Vec<Id> = self.variants.keys().cloned().collect();
+        for id in ids {
+            self.select_variant(id);
+        }
+    }
+
+    /// Set the locale used when a variant for the active locale is missing.
+    pub fn set_fallback_locale(&mut self, locale: impl Into<String>) {
+        self.fallback_locale = locale.into();
+    }
+
+    /// Point `id` at its best variant: active locale, fallback, locale-less,
+    /// then the alphabetically first one so the ID isn't dropped.
+    fn select_variant(&mut self, id: Id) {
+        let Some(variants) = self.variants.get(&id) else {
+            return;
+        };
+        let selected = variants
+            .get(&self.locale)
+            .or_else(|| variants.get(&self.fallback_locale))
+            .or_else(|| variants.get(""))
+            .or_else(|| {
+                let (locale, handle) = variants.iter().min_by_key(|(locale, _)| *locale)?;
+                warn!(
+                    "[{}] No '{}' or '{}' variant for an ID; using '{locale}'",
+                    type_name::<A>(),
+                    self.locale,
+                    self.fallback_locale
+                );
+                Some(handle)
+            })
+            .cloned();
+        match selected {
+            Some(handle) => {
+                self.put(id, handle);
+            }
+            None => {
+                self.take(&id);
+            }
+        }
+    }
+
+    /// Insert a handle, keeping the reverse map of [`Self::id_of`] in sync.
+    fn put(&mut self, id: Id, handle: Handle<A>) -> Option<Handle<A>> {
+        let asset_id = handle.id();
+        let previous = self.assets.insert(id.clone(), handle);
+        if let Some(previous) = &previous {
+            self.ids_by_asset.remove(&previous.id());
+        }
+        self.ids_by_asset.insert(asset_id, id);
+        previous
+    }
+
+    /// Remove a handle, keeping the reverse map of [`Self::id_of`] in sync.
+    fn take(&mut self, id: &Id) -> Option<Handle<A>> {
+        let handle = self.assets.remove(id)?;
+        self.ids_by_asset.remove(&handle.id());
+        Some(handle)
+    }
+
+    /// Remove all loaded handles and variants, keeping runtime settings.
+    fn clear_loaded(&mut self) {
+        self.assets.clear();
+        self.ids_by_asset.clear();
+        self.ids_by_asset_stale = false;
+        self.variants.clear();
+        self.source_paths.clear();
+        self.matched_extensions.clear();
+        self.subtypes.clear();
+        self.numeric_keys.clear();
+        self.ids_by_numeric_key.clear();
+    }
+
+    /// Remove everything registered for an ID, e.g. when its file is deleted.
+    fn forget(&mut self, id: &Id) {
+        self.take(id);
+        self.variants.remove(id);
+        self.source_paths.remove(id);
+        self.matched_extensions.remove(id);
+        self.subtypes.remove(id);
+        if let Some(key) = self.numeric_keys.remove(id) {
+            self.ids_by_numeric_key.remove(&key);
+        }
+    }
+
+    /// Drop every handle in the library so Bevy can free the assets.
+    ///
+    /// Clears the registered assets, variants and any staged reload. The
+    /// library settings and disabled IDs are kept. To also reset the load
+    /// state, send [`UnloadFolder`] instead.
+    pub fn unload_all(&mut self) {
+        self.clear_loaded();
+        self.staging = None;
+        self.preview_requests.clear();
+    }
+
+    /// Check if a staged reload is filling a library in the background.
+    ///
+    /// While staging, lookups keep returning the previous content; see
+    /// [`FolderLoaderPlugin::staged_reloads`].
+    #[must_use]
+    pub fn is_staging(&self) -> bool {
+        self.staging.is_some()
+    }
+
+    /// Start collecting registrations in a staging library.
+    ///
+    /// The staging library starts with the live library's disabled IDs,
+    /// freeze state, fallback and locales, so it resolves like the live one.
+    fn begin_staging(&mut self) {
+        let mut staging = Self::new();
+        staging.disabled.clone_from(&self.disabled);
+        staging.frozen = self.frozen;
+        staging.fallback = self.fallback.clone();
+        staging.locale.clone_from(&self.locale);
+        staging.fallback_locale.clone_from(&self.fallback_locale);
+        self.staging = Some(Box::new(staging));
+    }
+
+    /// Swap the staged content into the live library.
+    ///
+    /// Only the content moves; settings changed on the live library during
+    /// the reload are kept.
+    fn commit_staging(&mut self) {
+        if let Some(staging) = self.staging.take() {
+            self.assets = staging.assets;
+            self.variants = staging.variants;
+            self.source_paths = staging.source_paths;
+            self.matched_extensions = staging.matched_extensions;
+            self.subtypes = staging.subtypes;
+            self.numeric_keys = staging.numeric_keys;
+            self.ids_by_numeric_key = staging.ids_by_numeric_key;
+            self.ids_by_asset = staging.ids_by_asset;
+            self.ids_by_asset_stale = false;
+        }
+    }
+
+    /// Register a loaded handle, as a locale variant if `locale` is set.
+    fn register(&mut self, id: Id, locale: Option<&str>, path: &Path, handle: Handle<A>) {
+        if let Some(staging) = &mut self.staging {
+            staging.register(id, locale, path, handle);
+            return;
+        }
+        self.source_paths.insert(id.clone(), path.to_path_buf());
+        match locale {
+            Some(locale) => self.insert_variant(id, locale, handle),
+            None => {
+                self.put(id, handle);
+            }
+        }
+    }
+
+    /// Get the file extension the asset for an ID was loaded from.
+    ///
+    /// With [`FolderLoaderPlugin::with_extensions`] this tells which of the
+    /// configured extensions matched; it is `None` for unknown IDs.
+    #[must_use]
+    pub fn matched_extension(&self, id: Id) -> Option<&'static str> {
+        self.matched_extensions.get(&id).copied()
+    }
+
+    /// Get the file an ID was loaded from, relative to the asset source.
+    ///
+    /// Set whenever the loader registers an entry, including reloads and
+    /// files added at runtime. Handles inserted with [`Self::insert`] have
+    /// no path.
+    #[must_use]
+    pub fn path_of(&self, id: Id) -> Option<&Path> {
+        self.source_paths.get(&id).map(PathBuf::as_path)
+    }
+
+    /// Get the subtype tag parsed from an ID's filename.
+    ///
+    /// See [`FolderLoaderPlugin::with_id_subtype_delimiter`].
+    #[must_use]
+    pub fn subtype(&self, id: Id) -> Option<&str> {
+        self.subtypes.get(&id).map(String::as_str)
+    }
+
+    /// Request the preview of an ID to be loaded.
+    ///
+    /// Only has an effect with [`FolderLoaderPlugin::with_preview`]; the
+    /// preview handle then appears in the parallel `AssetFolder<Id, P>`.
+    pub fn request_preview(&mut self, id: Id) {
+        if !self.preview_requests.contains(&id) {
+            self.preview_requests.push(id);
+        }
+    }
+
+    /// Get the stable numeric key of an ID.
+    ///
+    /// Only available with [`FolderLoaderPlugin::with_numeric_keys`].
+    #[must_use]
+    pub fn numeric_key(&self, id: Id) -> Option<u64> {
+        self.numeric_keys.get(&id).copied()
+    }
+
+    /// Get the ID for a stable numeric key.
+    #[must_use]
+    pub fn id_from_numeric(&self, key: u64) -> Option<Id> {
+        self.ids_by_numeric_key.get(&key).cloned()
+    }
+
+    /// Record the numeric key of an ID, returning the ID it collides with.
+    fn insert_numeric_key(&mut self, id: Id, key: u64) -> Option<Id> {
+        if let Some(staging) = &mut self.staging {
+            return staging.insert_numeric_key(id, key);
+        }
+        let previous = self.ids_by_numeric_key.insert(key, id.clone());
+        self.numeric_keys.insert(id.clone(), key);
+        previous.filter(|previous| *previous != id)
+    }
+
+    /// Record the file extension an ID was loaded from.
+    fn insert_matched_extension(&mut self, id: Id, extension: &'static str) {
+        if let Some(staging) = &mut self.staging {
+            staging.insert_matched_extension(id, extension);
+            return;
+        }
+        self.matched_extensions.insert(id, extension);
+    }
+
+    /// Record the subtype tag parsed from an ID's filename.
+    fn insert_subtype(&mut self, id: Id, subtype: String) {
+        if let Some(staging) = &mut self.staging {
+            staging.insert_subtype(id, subtype);
+            return;
+        }
+        self.subtypes.insert(id, subtype);
+    }
+
+    /// Check if a handle is registered for an ID (and locale variant, if set).
+    fn is_registered(&self, id: Id, locale: Option<&str>) -> bool {
+        if let Some(staging) = &self.staging {
+            return staging.is_registered(id, locale);
+        }
+        match locale {
+            Some(locale) => self.variant(id, locale).is_some(),
+            None => self.assets.contains_key(&id),
+        }
+    }
+
+    /// Get all known IDs, excluding disabled ones.
+    ///
+    /// The order is unspecified and may differ between runs; see
+    /// [`Self::iter_sorted`] for a stable order.
+    pub fn keys(&self) -> impl Iterator<Item = Id> + '_ {
+        self.iter().map(|(id, _)| id)
+    }
+
+    /// Get references to all known IDs, excluding disabled ones.
+    ///
+    /// Unlike [`Self::keys`] this borrows rather than clones each ID.
+    pub fn keys_ref(&self) -> impl Iterator<Item = &Id> + '_ {
+        self.assets.keys().filter(|id| !self.disabled.contains(*id))
+    }
+
+    /// Returns the number of loaded assets, excluding disabled ones.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        let disabled = self
+            .disabled
+            .iter()
+            .filter(|id| self.assets.contains_key(*id))
+            .count();
+        self.assets.len() - disabled
+    }
+
+    /// Returns `true` if no enabled assets are loaded.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over all enabled IDs and their handles.
+    ///
+    /// The order is unspecified and may differ between runs; see
+    /// [`Self::iter_sorted`] for a stable order.
+    pub fn iter(&self) -> impl Iterator<Item = (Id, &Handle<A>)> + '_ {
+        self.assets
+            .iter()
+            .filter(|(id, _)| !self.disabled.contains(*id))
+            .map(|(id, h)| (id.clone(), h))
+    }
+
+    /// Iterates enabled entries ordered by their source file path.
+    ///
+    /// The order only depends on the file names, so it is identical across
+    /// runs, e.g. for UI lists and golden tests. Entries without a source
+    /// path (inserted with [`Self::insert`]) come last, in unspecified order.
+    pub fn iter_sorted(&self) -> impl Iterator<Item = (Id, &Handle<A>)> + '_ {
+        let mut entries: Vec<_> = self
+            .iter()
+            .map(|(id, handle)| (self.source_paths.get(&id), id, handle))
+            .collect();
+        entries.sort_by(|a, b| match (a.0, b.0) {
+            (Some(a), Some(b)) => a.cmp(b),
+            (a, b) => b.is_some().cmp(&a.is_some()),
+        });
+        entries.into_iter().map(|(_, id, handle)| (id, handle))
+    }
+
+    /// Returns a mutable iterator over all enabled IDs and their handles.
+    ///
+    /// Yields nothing on a frozen library, see [`Self::freeze`].
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Id, &mut Handle<A>)> + '_ {
+        let frozen = self.is_frozen_for("iter_mut");
+        self.ids_by_asset_stale |= !frozen;
+        let disabled = &self.disabled;
+        self.assets
+            .iter_mut()
+            .filter(move |(id, _)| !frozen && !disabled.contains(*id))
+            .map(|(id, h)| (id.clone(), h))
+    }
+
+    /// Returns the enabled IDs whose asset data isn't available in `assets` yet.
+    #[must_use]
+    pub fn pending_ids(&self, assets: &Assets<A>) -> Vec<Id> {
+        self.iter()
+            .filter(|(_, handle)| !assets.contains(*handle))
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    /// Returns an iterator over all enabled IDs and their asset data.
+    ///
+    /// Entries whose data isn't loaded yet are skipped.
+    pub fn iter_data<'a>(&'a self, assets: &'a Assets<A>) -> impl Iterator<Item = (Id, &'a A)> {
+        self.iter()
+            .filter_map(|(id, handle)| Some((id, assets.get(handle)?)))
+    }
+
+    /// Returns an iterator over all enabled entries whose data is loaded,
+    /// with both the handle and the data.
+    ///
+    /// Like [`Self::iter_data`], but keeps the handle for callers that store
+    /// it, e.g. on spawned entities. Entries that briefly point to unloaded
+    /// data, such as files re-registered by hot reloading, are skipped,
+    /// while [`Self::iter`] yields them.
+    pub fn iter_loaded<'a>(
+        &'a self,
+        assets: &'a Assets<A>,
+    ) -> impl Iterator<Item = (Id, &'a Handle<A>, &'a A)> {
+        self.iter()
+            .filter_map(|(id, handle)| Some((id, handle, assets.get(handle)?)))
+    }
+
+    /// Returns an owned snapshot of all enabled IDs and their handles.
+    ///
+    /// The snapshot doesn't borrow the library, so other resources can be
+    /// mutated while iterating it. Entries are in iteration order; see
+    /// [`Self::snapshot_sorted`] for a stable order.
+    #[must_use]
+    pub fn snapshot(&self) -> Vec<(Id, Handle<A>)> {
+        self.iter().map(|(id, h)| (id, h.clone())).collect()
+    }
+
+    /// Returns an owned snapshot of all enabled IDs and their handles, sorted by ID.
+    #[must_use]
+    pub fn snapshot_sorted(&self) -> Vec<(Id, Handle<A>)>
+    where
+        Id: Ord,
+    {
+        let mut snapshot = self.snapshot();
+        snapshot.sort_by(|a, b| a.0.cmp(&b.0));
+        snapshot
+    }
+
+    /// Set the function giving each asset its ordering weight.
+    ///
+    /// Set by [`FolderLoaderPlugin::with_sort_key_fn`].
+    pub fn set_sort_key_fn(&mut self, sort_key: fn(&A) -> i64) {
+        self.sort_key = Some(sort_key);
+    }
+
+    /// Iterates enabled entries ordered by their sort key, ties broken by ID.
+    ///
+    /// Entries whose data isn't loaded yet can't be weighed and come last.
+    /// Without a sort key function, entries are ordered by ID alone.
+    pub fn iter_by_sort_key(&self, assets: &Assets<A>) -> impl Iterator<Item = (Id, &Handle<A>)>
+    where
+        Id: Ord,
+    {
+        let mut entries: Vec<_> = self
+            .iter()
+            .map(|(id, handle)| {
+                let key = match (self.sort_key, assets.get(handle)) {
+                    (Some(sort_key), Some(asset)) => Some(sort_key(asset)),
+                    (None, _) => Some(0),
+                    (Some(_), None) => None,
+                };
+                (key.is_none(), key, id, handle)
+            })
+            .collect();
+        entries.sort_by(|a, b| (a.0, a.1, &a.2).cmp(&(b.0, b.1, &b.2)));
+        entries.into_iter().map(|(_, _, id, handle)| (id, handle))
+    }
+
+    /// Returns all IDs whose string form matches a simple glob pattern.
+    ///
+    /// `*` matches any run of characters (including none); every other
+    /// character matches literally. For example `fire*` matches by prefix,
+    /// `*_bolt` by suffix and `*ice*` by substring. Results are in
+    /// iteration order.
+    #[must_use]
+    pub fn find_matching(&self, pattern: &str) -> Vec<Id>
+    where
+        Id: std::fmt::Display,
+    {
+        self.keys()
+            .filter(|id| glob_matches(pattern, &id.to_string()))
+            .collect()
+    }
+
+    /// Direct access to underlying HashMap.
+    #[must_use]
+    pub fn assets(&self) -> &HashMap<Id, Handle<A>> {
+        &self.assets
+    }
+
+    /// Mutable access to underlying HashMap.
+    ///
+    /// Not guarded by [`Self::freeze`], so it stays available as an explicit
+    /// escape hatch.
+    #[must_use]
+    pub fn assets_mut(&mut self) -> &mut HashMap<Id, Handle<A>> {
+        self.ids_by_asset_stale = true;
+        &mut self.assets
+    }
+}
+
+#[cfg(feature = "test-util")]
+impl<Id, A> AssetFolder<Id, A>
+where
+    Id: Clone + Eq + Hash + Send + Sync + FromFilename + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
+{
+    /// Fill the library with `count` default handles under the IDs
+    /// `dummy_0` to `dummy_{count - 1}`, for benchmarking lookups at scale.
+    pub fn populate_dummy(&mut self, count: usize) {
+        self.assets.reserve(count);
+        for index in 0..count {
+            self.put(
+                Id::from_filename(&format!("dummy_{index}")),
+                Handle::default(),
+            );
+        }
+    }
+}
+
+// =============================================================================
+// Messages
+// =============================================================================
+
+/// Sent once the folder contents are known, before the assets finish loading.
+///
+/// Fires on the frame discovery completes, so a progress UI can be set up
+/// with the final denominator. Registered by [`FolderLoaderPlugin`].
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct FolderDiscovered<A: Asset> {
+    /// Number of matching files that will be loaded.
+    pub total_files: usize,
+    _marker: PhantomData<A>,
+}
+
+impl<A: Asset> FolderDiscovered<A> {
+    /// Create the message for a folder with `total_files` matching files.
+    #[must_use]
+    pub fn new(total_files: usize) -> Self {
+        Self {
+            total_files,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Sent for each asset as the loading system registers it in the library.
+///
+/// Lets menus fill in while a long load is still running. Sent once per
+/// registered file of a folder load (once per variant in locale-variant
+/// mode); assets added later by hot reloading don't send it. Registered by
+/// [`FolderLoaderPlugin`].
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct AssetRegistered<Id: Send + Sync + 'static, A: Asset> {
+    /// ID the asset was registered under.
+    pub id: Id,
+    /// Handle to the registered asset.
+    pub handle: Handle<A>,
+}
+
+/// Sent once per folder load, on the frame the folder is processed.
+///
+/// Also sent when the folder itself fails to load, with the failure listed
+/// and nothing loaded. Registered by [`FolderLoaderPlugin`].
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct FolderLoadCompleted<A: Asset> {
+    /// Path of the folder, relative to the asset source.
+    pub folder_path: &'static str,
+    /// Number of assets registered from the folder.
+    pub loaded: usize,
+    /// Paths that failed to load.
+    pub failed: Vec<String>,
+    _marker: PhantomData<A>,
+}
+
+impl<A: Asset> FolderLoadCompleted<A> {
+    /// Create the message for a processed folder.
+    #[must_use]
+    pub fn new(folder_path: &'static str, loaded: usize, failed: Vec<String>) -> Self {
+        Self {
+            folder_path,
+            loaded,
+            failed,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Unloads a folder, releasing every handle the loader holds for it.
+///
+/// The loading system empties the [`AssetFolder`], drops the folder and
+/// asset handles and resets the [`AssetFolderHandle`]. The folder stays
+/// unloaded until [`AssetFolderHandle::request_reload`] is called; folders
+/// with a lower priority that start in the meantime wait for it. Registered
+/// by [`FolderLoaderPlugin`].
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct UnloadFolder<A: Asset> {
+    _marker: PhantomData<A>,
+}
+
+impl<A: Asset> UnloadFolder<A> {
+    /// Create the unload message.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<A: Asset> Default for UnloadFolder<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// =============================================================================
+// Entities
+// =============================================================================
+
+/// Component linking an entity to an asset of an [`AssetFolder`].
+///
+/// Spawned by [`SpawnFromFolder`], one entity per asset.
+#[derive(Component, Reflect, Debug, Clone, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct AssetRef<Id: Send + Sync + 'static, A: Asset> {
+    /// ID of the asset in the library.
+    pub id: Id,
+    /// Handle to the asset.
+    pub handle: Handle<A>,
+}
+
+/// Command spawning an entity with an [`AssetRef`] for each enabled asset of
+/// the [`AssetFolder`].
+///
+/// Entities are spawned in [`AssetFolder::iter_sorted`] order. Queue it once
+/// the library is ready, e.g. from a system gated by [`folder_became_ready`]:
+///
+/// ```rust
+/// # use msg_load_folder::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Asset, Clone, Reflect)]
+/// # struct Spell;
+/// # #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+/// # struct SpellId(u64);
+/// # impl From<String> for SpellId { fn from(s: String) -> Self { SpellId(s.len() as u64) } }
+/// fn spawn_spells(mut commands: Commands) {
+///     commands.queue(SpawnFromFolder::<SpellId, Spell>::new());
+/// }
+/// ```
+pub struct SpawnFromFolder<Id, A> {
+    _marker: PhantomData<fn() -> (Id, A)>,
+}
+
+impl<Id, A> SpawnFromFolder<Id, A> {
+    /// Create the spawn command.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Id, A> Default for SpawnFromFolder<Id, A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Id, A> Command for SpawnFromFolder<Id, A>
+where
+    Id: Clone + Eq + Hash + Send + Sync + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
+{
+    fn apply(self, world: &mut World) {
+        let Some(library) = world.get_resource::<AssetFolder<Id, A>>() else {
+            warn!(
+                "[{}] No AssetFolder to spawn entities from",
+                type_name::<A>()
+            );
+            return;
+        };
+        let refs: Vec<_> = library
+            .iter_sorted()
+            .map(|(id, handle)| AssetRef {
+                id,
+                handle: handle.clone(),
+            })
+            .collect();
+        world.spawn_batch(refs);
+    }
+}
+
+// =============================================================================
+// Run Conditions
+// =============================================================================
+
+/// Run condition that is true only on the frame the library first becomes ready.
+///
+/// Useful for one-shot setup that depends on loaded content, without an
+/// extra flag resource:
+///
+/// ```rust
+/// # use msg_load_folder::prelude::*;
+/// # use bevy::prelude::*;
+/// # #[derive(Asset, Clone, Reflect)]
+/// # struct Spell;
+/// # #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+/// # struct SpellId(u64);
+/// # fn setup_spell_ui() {}
+/// # fn example(app: &mut App) {
+/// app.add_systems(Update, setup_spell_ui.run_if(folder_became_ready::<SpellId, Spell>));
+/// # }
+/// ```
+pub fn folder_became_ready<Id, A>(
+    library: Option<Res<AssetFolder<Id, A>>>,
+    mut was_ready: Local<bool>,
+) -> bool
+where
+    Id: Clone + Eq + Hash + Send + Sync + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
+{
+    let is_ready = library.is_some_and(|library| library.is_ready());
+    let became_ready = is_ready && !*was_ready;
+    *was_ready = is_ready;
+    became_ready
+}
+
+// =============================================================================
+// Loading System
+// =============================================================================
+
+/// The defaults file of a folder, see [`FolderLoaderPlugin::with_folder_defaults`].
+#[derive(Default)]
+enum FolderDefaults<A: Asset> {
+    /// No defaults file is configured, or it failed to load.
+    #[default]
+    None,
+    /// The defaults file is still loading.
+    Loading(Handle<A>),
+    /// The defaults, copied once so the file may unload or change.
+    Loaded(A),
+}
+
+/// A discovered file waiting for its asset data.
+struct PendingAsset<Id, A: Asset> {
+    id: Id,
+    locale: Option<String>,
+    path: PathBuf,
+    /// `None` while the load is held back by the concurrency hint.
+    handle: Option<Handle<A>>,
+}
+
+/// Files listed without loading them, each with its ID if one is given
+/// explicitly, and the manifest's missing files with their ID; or the listed
+/// path with the error.
+type ListedFiles = Result<
+    (Vec<(PathBuf, Option<String>)>, Vec<(PathBuf, String)>),
+    (&'static str, FolderLoadError),
+>;
+
+/// A file of a resolved folder with its ID and locale or skip reason.
+type DiscoveredFile<Id, T> = (PathBuf, Result<(Id, Option<String>), SkipReason>, T);
+
+/// Run condition skipping the loading system once its work is done.
+///
+/// The system only has to run while the folder loads and when a reload,
+/// retry or unload is requested.
+fn folder_needs_loading<A: Asset>(
+    folder_handle: Res<AssetFolderHandle<A>>,
+    unload: MessageReader<UnloadFolder<A>>,
+) -> bool {
+    !folder_handle.processed || folder_handle.reload_requested || !unload.is_empty()
+}
+
+/// Generic system that loads assets from folders.
+///
+/// This system:
+/// 1. Initiates folder loading via AssetServer::load_folder
+/// 2. Waits for the LoadedFolder to be available
+/// 3. Discovers all handles once, extracting IDs from filenames
+/// 4. Populates the AssetFolder with ID -> Handle mappings as asset data
+///    becomes available, marking the folder processed once none are pending
+fn load_assets_from_folder<Id, A>(
+    asset_server: Res<AssetServer>,
+    config: Res<SharedConfig<Id, A>>,
+    mut folder_handle: ResMut<AssetFolderHandle<A>>,
+    loaded_folders: Res<Assets<LoadedFolder>>,
+    mut assets: ResMut<Assets<A>>,
+    mut library: ResMut<AssetFolder<Id, A>>,
+    mut priorities: ResMut<FolderLoadPriorities>,
+    mut pending: Local<Vec<PendingAsset<Id, A>>>,
+    mut defaults: Local<FolderDefaults<A>>,
+    mut listed_ids: Local<HashMap<PathBuf, String>>,
+    time: Option<Res<Time>>,
+    mut discovered: MessageWriter<FolderDiscovered<A>>,
+    mut completed: MessageWriter<FolderLoadCompleted<A>>,
+    mut registered: MessageWriter<AssetRegistered<Id, A>>,
+    mut unload: MessageReader<UnloadFolder<A>>,
+) where
+    Id: Clone + Eq + Hash + Send + Sync + Default + FromFilename + std::fmt::Debug + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
+{
+    if let Some(time) = time {
+        folder_handle.tick(time.elapsed());
+    }
+
+    // Release everything on request, then stay idle until a reload
+    if unload.read().count() > 0 {
+        info!(
+            "[{}] Unloading folder '{}'",
+            type_name::<A>(),
+            config.folder_path
+        );
+        pending.clear();
+        *defaults = FolderDefaults::None;
+        library.unload_all();
+        *folder_handle = AssetFolderHandle {
+            unloaded: true,
+            ..AssetFolderHandle::new()
+        };
+        priorities.set_processed::<A>(false);
+        return;
+    }
+
+    // Handle a requested rescan, unless nothing changed on disk
+    if folder_handle.reload_requested {
+        let last_modified = config.last_modified();
+        if last_modified.is_some() && last_modified == folder_handle.last_modified {
+            folder_handle.reload_requested = false;
+            debug!(
+                "[{}] Folder '{}' unchanged, skipping reload",
+                type_name::<A>(),
+                config.folder_path
+            );
+        } else {
+            info!(
+                "[{}] Reloading folder '{}'",
+                type_name::<A>(),
+                config.folder_path
+            );
+            folder_handle.reset();
+            if config.staged_reloads {
+                library.begin_staging();
+            } else {
+                library.clear_loaded();
+            }
+            priorities.set_processed::<A>(false);
+            // Start loading next frame, once the old folder handle is released
+            return;
+        }
+    }
+
+    if folder_handle.unloaded {
+        return;
+    }
+
+    // Mirror the remote source's remaining download size while loading
+    if let Some(counter) = &config.pending_bytes {
+        folder_handle.pending_bytes = (!folder_handle.processed).then(|| counter.get());
+    }
+
+    // Start loading the folder if we haven't yet, once higher priorities are done
+    if !folder_handle.is_started() {
+        if priorities.is_blocked(config.priority) {
+            return;
+        }
+        pending.clear();
+        listed_ids.clear();
+        *defaults = match config.folder_defaults {
+            Some((file_name, _)) => FolderDefaults::Loading(
+                asset_server.load(format!("{}/{file_name}", config.folder_path)),
+            ),
+            None => FolderDefaults::None,
+        };
+        folder_handle.mark_progress();
+        folder_handle.started_at = Some(folder_handle.now);
+        folder_handle.last_modified = config.last_modified();
+        #[cfg(feature = "manifest")]
+        if let Some(manifest_path) = config.manifest {
+            let listing = read_manifest(asset_server.clone(), manifest_path, config.folder_path);
+            folder_handle.listing = Some(IoTaskPool::get().spawn(listing));
+            return;
+        }
+        if config.two_pass {
+            let listing = list_folder(
+                asset_server.clone(),
+                config.folders().collect(),
+                config.empty_id_files(),
+            );
+            folder_handle.listing = Some(IoTaskPool::get().spawn(listing));
+        } else {
+            folder_handle.handle = Some(asset_server.load_folder(config.folder_path));
+            folder_handle.extra_handles = config
+                .extra_folders
+                .iter()
+                .map(|folder| asset_server.load_folder(*folder))
+                .collect();
+        }
+        return;
+    }
+
+    // Skip if already processed
+    if folder_handle.processed {
+        return;
+    }
+
+    // Re-attempt paths handed back by `take_problems`
+    if !folder_handle.retry.is_empty() {
+        for path in std::mem::take(&mut folder_handle.retry)
+            .into_iter()
+            .map(PathBuf::from)
+        {
+            // Files listed with an explicit ID keep it
+            let classified = match listed_ids.get(&path) {
+                Some(id) => Ok((Id::from_filename(id), None)),
+                None => config.classify(&path),
+            };
+            let (id, locale) = match classified {
+                Ok(id) => id,
+                Err(reason) => {
+                    config.skip(&path, reason);
+                    folder_handle.skipped_paths.push((path.clone(), reason));
+                    continue;
+                }
+            };
+            debug!("[{}] Retrying '{}'", type_name::<A>(), path.display());
+            let handle: Handle<A> = asset_server.load(path.clone());
+            if let Some(LoadState::Failed(_)) = asset_server.get_load_state(&handle) {
+                asset_server.reload(path.clone());
+            }
+            pending.push(PendingAsset {
+                id,
+                locale,
+                path,
+                handle: Some(handle),
+            });
+        }
+        // A failed folder is not resolved again; only the retried paths load
+        folder_handle.discovered = true;
+    }
+
+    let was_discovered = folder_handle.discovered;
+
+    // Two-pass discovery: list the folder, then load only matching files
+    if let Some(listing) = &mut folder_handle.listing {
+        let Some(result) = check_ready(listing) else {
+            return;
+        };
+        folder_handle.listing = None;
+        match result {
+            Ok((paths, missing)) => {
+                for (path, id) in missing {
+                    folder_handle
+                        .missing_listed
+                        .push((path.display().to_string(), id.clone()));
+                    fail_or_substitute(
+                        &config,
+                        &asset_server,
+                        &mut library,
+                        &mut folder_handle,
+                        (&Id::from_filename(&id), None, &path),
+                        "listed in the manifest but missing".to_string(),
+                    );
+                    listed_ids.insert(path, id);
+                }
+                let files = paths.into_iter().map(|(path, id)| {
+                    let classified = match id {
+                        Some(id) => {
+                            let classified = Ok((Id::from_filename(&id), None));
+                            listed_ids.insert(path.clone(), id);
+                            classified
+                        }
+                        None => config.classify(&path),
+                    };
+                    (path.clone(), classified, path)
+                });
+                discover_folder(
+                    &config,
+                    files,
+                    |_| Ok(None),
+                    &mut folder_handle,
+                    &mut pending,
+                );
+                // Loads start once the limit has dropped the files beyond it
+                if config.concurrency_hint.is_none() {
+                    for entry in &mut pending {
+                        entry.handle = Some(asset_server.load(entry.path.clone()));
+                    }
+                }
+            }
+            Err((folder_path, err)) => {
+                folder_handle
+                    .failures
+                    .push((folder_path.to_string(), err.to_string()));
+                if matches!(err, FolderLoadError::FolderNotFound { .. }) {
+                    folder_not_found(folder_path, &mut folder_handle);
+                } else {
+                    warn!("[{}] {err}", type_name::<A>());
+                    folder_handle.folder_error = Some((folder_path.to_string(), err));
+                }
+                library.staging = None;
+                folder_handle.mark_processed();
+                priorities.set_processed::<A>(true);
+                completed.write(folder_handle.completed(config.folder_path));
+                return;
+            }
+        }
+    }
+
+    // Extracted IDs are picked up from the frame after the task started
+    if let Some(task) = &mut folder_handle.id_extraction {
+        let Some(files) = check_ready(task) else {
+            return;
+        };
+        folder_handle.id_extraction = None;
+        if let Ok(files) = files.downcast::<Vec<DiscoveredFile<Id, UntypedHandle>>>() {
+            discover_typed(
+                &config,
+                &asset_server,
+                *files,
+                &mut folder_handle,
+                &mut pending,
+                &mut library,
+            );
+        }
+    } else if !folder_handle.discovered {
+        let Some(first) = folder_handle.handle.clone() else {
+            return;
+        };
+        let folder_handles: Vec<_> = std::iter::once(first)
+            .chain(folder_handle.extra_handles.iter().cloned())
+            .collect();
+
+        // A single unloadable file fails the whole LoadedFolder
+        let failed = folder_handles
+            .iter()
+            .zip(config.folders())
+            .find_map(
+                |(handle, folder)| match asset_server.get_load_state(handle) {
+                    Some(LoadState::Failed(err)) => Some((folder, err)),
+                    _ => None,
+                },
+            );
+        if let Some((folder_path, err)) = failed {
+            let path = match &*err {
+                AssetLoadError::AssetLoaderError(err) => err.path().path().display().to_string(),
+                _ => folder_path.to_string(),
+            };
+            if matches!(
+                &*err,
+                AssetLoadError::AssetReaderError(AssetReaderError::NotFound(_))
+            ) {
+                folder_not_found(folder_path, &mut folder_handle);
+            } else {
+                warn!(
+                    "[{}] Failed to load folder '{folder_path}': {err}",
+                    type_name::<A>()
+                );
+            }
+            folder_handle.failures.push((path, err.to_string()));
+            // Keep the previous content of a failed staged reload
+            library.staging = None;
+            folder_handle.mark_processed();
+            priorities.set_processed::<A>(true);
+            completed.write(folder_handle.completed(config.folder_path));
+            return;
+        }
+
+        // Wait for all folders to be loaded
+        let Some(folders) = folder_handles
+            .iter()
+            .map(|handle| loaded_folders.get(handle))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return;
+        };
+        // Sorted by path within each folder, so duplicates resolve the same
+        // way whatever order the filesystem lists files in
+        let files = folders.into_iter().flat_map(|folder| {
+            let mut files: Vec<_> = folder
+                .handles
+                .iter()
+                .filter_map(|handle| {
+                    let path = handle.path()?.path().to_path_buf();
+                    Some((path, handle.clone()))
+                })
+                .collect();
+            files.sort_by(|a, b| a.0.cmp(&b.0));
+            files
+        });
+
+        // Derive the IDs off the main thread, only handles and paths move
+        if config.async_id_extraction {
+            let files: Vec<_> = files.collect();
+            let config = Arc::clone(&config.0);
+            let task = AsyncComputeTaskPool::get().spawn(async move {
+                let files: Vec<DiscoveredFile<Id, UntypedHandle>> = files
+                    .into_iter()
+                    .map(|(path, handle)| {
+                        let classified = config.classify(&path);
+                        (path, classified, handle)
+                    })
+                    .collect();
+                Box::new(files) as Box<dyn Any + Send>
+            });
+            folder_handle.id_extraction = Some(task);
+            return;
+        } else {
+            let files = files.map(|(path, handle)| {
+                let classified = config.classify(&path);
+                (path, classified, handle)
+            });
+            discover_typed(
+                &config,
+                &asset_server,
+                files,
+                &mut folder_handle,
+                &mut pending,
+                &mut library,
+            );
+        }
+    }
+
+    if folder_handle.discovered && !was_discovered {
+        if folder_handle.total == 0 {
+            warn!(
+                "[{}] Folder '{}' loaded but contained no matching files",
+                type_name::<A>(),
+                config.folder_path
+            );
+        }
+        discovered.write(FolderDiscovered::new(folder_handle.total));
+    }
+
+    // Wait for the folder defaults before registering anything, then keep
+    // a single copy of them for the rest of the load
+    if let FolderDefaults::Loading(handle) = &*defaults {
+        match asset_server.load_state(handle) {
+            LoadState::Loaded => {
+                *defaults = match assets.get(handle) {
+                    Some(asset) => FolderDefaults::Loaded(asset.clone()),
+                    None => FolderDefaults::None,
+                };
+            }
+            LoadState::Failed(err) => {
+                let path = config
+                    .folder_defaults
+                    .map(|(file_name, _)| format!("{}/{file_name}", config.folder_path))
+                    .unwrap_or_default();
+                warn!(
+                    "[{}] Failed to load folder defaults '{path}': {err}",
+                    type_name::<A>()
+                );
+                folder_handle.failures.push((path, err.to_string()));
+                *defaults = FolderDefaults::None;
+            }
+            _ => return,
+        }
+    }
+
+    // Start held-back loads as earlier ones finish
+    if let Some(max) = config.concurrency_hint {
+        let in_flight = pending
+            .iter()
+            .filter(|entry| {
+                entry
+                    .handle
+                    .as_ref()
+                    .is_some_and(|handle| !assets.contains(handle))
+            })
+            .count();
+        for entry in pending
+            .iter_mut()
+            .filter(|entry| entry.handle.is_none())
+            .take(max.saturating_sub(in_flight))
+        {
+            entry.handle = Some(asset_server.load(entry.path.clone()));
+        }
+    }
+
+    // Register entries whose data has become available
+    pending.retain(|entry| {
+        let Some(handle) = &entry.handle else {
+            return true;
+        };
+        if let Some(LoadState::Failed(err)) = asset_server.get_load_state(handle) {
+            folder_handle
+                .failed_assets
+                .push(entry.path.display().to_string());
+            fail_or_substitute(
+                &config,
+                &asset_server,
+                &mut library,
+                &mut folder_handle,
+                (&entry.id, entry.locale.as_deref(), &entry.path),
+                err.to_string(),
+            );
+            return false;
+        }
+        if !assets.contains(handle) {
+            return true;
+        }
+        if config.wait_for_dependencies {
+            match asset_server.get_recursive_dependency_load_state(handle) {
+                Some(RecursiveDependencyLoadState::Failed(err)) => {
+                    let path = match &*err {
+                        AssetLoadError::AssetLoaderError(err) => {
+                            err.path().path().display().to_string()
+                        }
+                        _ => entry.path.display().to_string(),
+                    };
+                    warn!(
+                        "[{}] Failed to load a dependency of '{}': {err}",
+                        type_name::<A>(),
+                        entry.path.display()
+                    );
+                    folder_handle.failures.push((path, err.to_string()));
+                }
+                Some(RecursiveDependencyLoadState::Loaded) => {}
+                _ => return true,
+            }
+        }
+
+        if let (FolderDefaults::Loaded(folder_defaults), Some((_, merge))) =
+            (&*defaults, config.folder_defaults)
+            && let Some(asset) = assets.get_mut(handle)
+        {
+            merge(asset, folder_defaults);
+        }
+        if let Some(asset) = assets.get(handle)
+            && !config.validate(&mut folder_handle, &entry.path, asset)
+        {
+            return false;
+        }
+        register_entry(
+            &config,
+            &mut library,
+            (&entry.id, entry.locale.as_deref(), &entry.path),
+            handle.clone(),
+        );
+        load_dependencies(&config, &asset_server, &assets, handle, &mut folder_handle);
+        folder_handle
+            .loaded_ids
+            .push(loaded_label(&entry.id, entry.locale.as_deref()));
+        registered.write(AssetRegistered {
+            id: entry.id.clone(),
+            handle: handle.clone(),
+        });
+        debug!(
+            "[{}] Registered asset handle: {:?} ({})",
+            type_name::<A>(),
+            entry.id,
+            entry.path.display()
+        );
+        false
+    });
+
+    // Report progress only when the counts change
+    folder_handle.pending = pending.len();
+    let loaded = folder_handle.loaded_ids.len();
+    if loaded != folder_handle.loaded {
+        folder_handle.loaded = loaded;
+        folder_handle.mark_progress();
+        if let Some(on_progress) = config.on_progress {
+            on_progress(loaded, folder_handle.total);
+        }
+    }
+
+    if !pending.is_empty() {
+        return;
+    }
+
+    // Wait for referenced assets if requested
+    if config.wait_for_dependencies && !dependencies_resolved(&asset_server, &mut folder_handle) {
+        return;
+    }
+
+    // Mark as processed
+    library.commit_staging();
+    if config.compact_on_load {
+        library.compact();
+    }
+    folder_handle.mark_processed();
+    if !config.retain_folder_handle && !config.hot_reload {
+        folder_handle.handle = None;
+        folder_handle.extra_handles.clear();
+    }
+    priorities.set_processed::<A>(true);
+    completed.write(folder_handle.completed(config.folder_path));
+
+    info!(
+        "[{}] Processed {} asset handles from folder '{}' in {:?}",
+        type_name::<A>(),
+        library.len(),
+        config.folder_path,
+        folder_handle.load_duration.unwrap_or_default()
+    );
+}
+
+/// Copies the counts of a folder into the shared [`FolderLoadProgress`].
+fn update_folder_progress<A: Asset>(
+    folder_handle: Res<AssetFolderHandle<A>>,
+    mut progress: ResMut<FolderLoadProgress>,
+) {
+    progress.set::<A>(
+        folder_handle.total,
+        folder_handle.loaded,
+        folder_handle.processed,
+    );
+}
+
+/// Loads the previews requested through [`AssetFolder::request_preview`].
+fn load_requested_previews<Id, A, P>(
+    asset_server: Res<AssetServer>,
+    config: Res<PreviewConfig<Id, A, P>>,
+    mut library: ResMut<AssetFolder<Id, A>>,
+    mut previews: ResMut<AssetFolder<Id, P>>,
+) where
+    Id: Clone + Eq + Hash + Send + Sync + std::fmt::Debug + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
+    P: Asset + Clone,
+{
+    if library.preview_requests.is_empty() {
+        return;
+    }
+    for id in std::mem::take(&mut library.preview_requests) {
+        if previews.assets.contains_key(&id) {
+            continue;
+        }
+        let Some(path) = library
+            .source_paths
+            .get(&id)
+            .and_then(|path| config.preview_path(path))
+        else {
+            warn!("[{}] No preview source for {:?}", type_name::<A>(), id);
+            continue;
+        };
+        debug!(
+            "[{}] Loading preview '{}'",
+            type_name::<A>(),
+            path.display()
+        );
+        previews.insert(id, asset_server.load(path));
+    }
+}
+
+/// Registers single assets added to the folder after it has been processed.
+///
+/// Reacts to `AssetEvent::Added` for newly-loaded assets whose path lies in
+/// the configured folder and yields an ID, without re-running discovery.
+/// Assets that are already registered are ignored; a different file claiming
+/// a registered ID is resolved by the [`DuplicatePolicy`].
+fn register_added_assets<Id, A>(
+    asset_server: Res<AssetServer>,
+    config: Res<SharedConfig<Id, A>>,
+    mut folder_handle: ResMut<AssetFolderHandle<A>>,
+    mut library: ResMut<AssetFolder<Id, A>>,
+    assets: Res<Assets<A>>,
+    mut asset_events: MessageReader<AssetEvent<A>>,
+) where
+    Id: Clone + Eq + Hash + Send + Sync + Default + FromFilename + std::fmt::Debug + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
+{
+    // A manifest lists every file, other files in the folder don't count
+    #[cfg(feature = "manifest")]
+    let listed_only = config.manifest.is_some();
+    #[cfg(not(feature = "manifest"))]
+    let listed_only = false;
+    if !folder_handle.processed || listed_only {
+        asset_events.clear();
+        return;
+    }
+
+    for event in asset_events.read() {
+        let AssetEvent::Added { id } = event else {
+            continue;
+        };
+        let Some(path) = asset_server.get_path(*id) else {
+            continue;
+        };
+        let path = path.path();
+        if !config.folders().any(|folder| path.starts_with(folder)) {
+            continue;
+        }
+        let Some((asset_id, locale)) = config.id_for(path) else {
+            config.skip(path, config.skip_reason(path));
+            continue;
+        };
+        let Some(handle) = asset_server.get_id_handle(*id) else {
+            continue;
+        };
+        let replaces = library.is_registered(asset_id.clone(), locale.as_deref());
+        if replaces {
+            // A different file claiming a registered ID is a duplicate
+            let Some(first) = library
+                .path_of(asset_id.clone())
+                .filter(|first| locale.is_none() && *first != path)
+                .filter(|_| {
+                    !library
+                        .get_including_disabled(asset_id.clone())
+                        .is_some_and(|registered| same_asset(registered, &handle))
+                })
+                .map(Path::to_path_buf)
+            else {
+                continue;
+            };
+            report_duplicate(&config, &mut folder_handle, (&asset_id, None), &first, path);
+            if config.duplicate_policy == DuplicatePolicy::KeepFirst {
+                continue;
+            }
+        }
+        if let Some(asset) = assets.get(&handle)
+            && !config.validate(&mut folder_handle, path, asset)
+        {
+            continue;
+        }
+
+        load_dependencies(&config, &asset_server, &assets, &handle, &mut folder_handle);
+        register_entry(
+            &config,
+            &mut library,
+            (&asset_id, locale.as_deref(), path),
+            handle,
+        );
+        if !replaces {
+            folder_handle
+                .loaded_ids
+                .push(loaded_label(&asset_id, locale.as_deref()));
+            folder_handle.total += 1;
+            folder_handle.loaded += 1;
+        }
+        info!(
+            "[{}] Registered added asset {:?} ({})",
+            type_name::<A>(),
+            asset_id,
+            path.display()
+        );
+    }
+}
+
+/// Loads the assets referenced by a registered asset, if configured.
+fn load_dependencies<Id, A>(
+    config: &FolderLoaderConfig<Id, A>,
+    asset_server: &AssetServer,
+    assets: &Assets<A>,
+    handle: &Handle<A>,
+    folder_handle: &mut AssetFolderHandle<A>,
+) where
+    Id: Clone + Eq + Hash + Send + Sync + Default + FromFilename + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
+{
+    let (Some(dependency_fn), Some(asset)) = (config.dependency_fn, assets.get(handle)) else {
+        return;
+    };
+    for path in dependency_fn(asset) {
+        debug!("[{}] Loading dependency '{path}'", type_name::<A>());
+        folder_handle
+            .dependencies
+            .push(asset_server.load_untyped(path));
+    }
+}
+
+/// Checks whether all dependencies finished loading, recording failures.
+fn dependencies_resolved<A: Send + Sync + 'static>(
+    asset_server: &AssetServer,
+    folder_handle: &mut AssetFolderHandle<A>,
+) -> bool {
+    let mut failures = Vec::new();
+    for dependency in &folder_handle.dependencies {
+        match asset_server.load_state(dependency) {
+            LoadState::Loaded => {}
+            LoadState::Failed(err) => {
+                let path = asset_server
+                    .get_path(dependency)
+                    .map_or_else(String::new, |path| path.to_string());
+                failures.push((path, err.to_string()));
+            }
+            _ => return false,
+        }
+    }
+    for (path, reason) in failures {
+        warn!(
+            "[{}] Failed to load dependency '{path}': {reason}",
+            type_name::<A>()
+        );
+        folder_handle.failures.push((path, reason));
+    }
+    true
+}
+
+/// Applies a reloaded `LoadedFolder` to the library in hot-reload mode.
+///
+/// Files missing from the reloaded folder have their IDs dropped, and
+/// loaded files whose IDs aren't registered yet are added. Files still
+/// loading are picked up by [`register_added_assets`].
+fn sync_reloaded_folder<Id, A>(
+    config: Res<SharedConfig<Id, A>>,
+    mut folder_handle: ResMut<AssetFolderHandle<A>>,
+    mut library: ResMut<AssetFolder<Id, A>>,
+    loaded_folders: Res<Assets<LoadedFolder>>,
+    assets: Res<Assets<A>>,
+    mut folder_events: MessageReader<AssetEvent<LoadedFolder>>,
+) where
+    Id: Clone + Eq + Hash + Send + Sync + Default + FromFilename + std::fmt::Debug + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
+{
+    let Some(first) = folder_handle.handle.as_ref() else {
+        folder_events.clear();
+        return;
+    };
+    let folder_ids: Vec<_> = std::iter::once(first)
+        .chain(&folder_handle.extra_handles)
+        .map(Handle::id)
+        .collect();
+    let reloaded = folder_events
+        .read()
+        .any(|event| folder_ids.iter().any(|id| event.is_modified(*id)));
+    if !config.hot_reload || !folder_handle.processed || !reloaded {
+        return;
+    }
+    let Some(folders) = folder_ids
+        .iter()
+        .map(|id| loaded_folders.get(*id))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return;
+    };
+
+    let files: Vec<(PathBuf, Handle<A>)> = folders
+        .into_iter()
+        .flat_map(|folder| &folder.handles)
+        .filter_map(|handle| {
+            let path = handle.path()?.path().to_path_buf();
+            Some((path, handle.clone().try_typed().ok()?))
+        })
+        .collect();
+
+    let removed: Vec<Id> = library
+        .source_paths
+        .iter()
+        .filter(|(_, path)| !files.iter().any(|(file, _)| file == *path))
+        .map(|(id, _)| id.clone())
+        .collect();
+    for id in removed {
+        info!(
+            "[{}] Dropping {:?}: its file was removed",
+            type_name::<A>(),
+            id
+        );
+        library.forget(&id);
+        let label = loaded_label(&id, None);
+        folder_handle.loaded_ids.retain(|loaded| *loaded != label);
+        folder_handle.total = folder_handle.total.saturating_sub(1);
+        folder_handle.loaded = folder_handle.loaded.saturating_sub(1);
+    }
+
+    for (path, handle) in files {
+        let Some((id, locale)) = config.id_for(&path) else {
+            continue;
+        };
+        if library.is_registered(id.clone(), locale.as_deref()) {
+            continue;
+        }
+        let Some(asset) = assets.get(&handle) else {
+            continue;
+        };
+        if !config.validate(&mut folder_handle, &path, asset) {
+            continue;
+        }
+        register_entry(
+            &config,
+            &mut library,
+            (&id, locale.as_deref(), &path),
+            handle,
+        );
+        folder_handle
+            .loaded_ids
+            .push(loaded_label(&id, locale.as_deref()));
+        folder_handle.total += 1;
+        folder_handle.loaded += 1;
+        debug!(
+            "[{}] Hot-reloaded asset handle: {:?} ({})",
+            type_name::<A>(),
+            id,
+            path.display()
+        );
+    }
+}
+
+/// Registers a handle in the library, recording its numeric key if enabled.
+fn register_entry<Id, A>(
+    config: &FolderLoaderConfig<Id, A>,
+    library: &mut AssetFolder<Id, A>,
+    (id, locale, path): (&Id, Option<&str>, &Path),
+    handle: Handle<A>,
+) where
+    Id: Clone + Eq + Hash + Send + Sync + Default + FromFilename + std::fmt::Debug + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
+{
+    library.register(id.clone(), locale, path, handle);
+    if let Some(extension) = config.extension_of(path) {
+        library.insert_matched_extension(id.clone(), extension);
+    }
+    if let Some(subtype) = config.subtype_for(path) {
+        library.insert_subtype(id.clone(), subtype);
+    }
+    let Some(key) = config.numeric_key_for(path) else {
+        return;
+    };
+    if let Some(other) = library.insert_numeric_key(id.clone(), key) {
+        warn!(
+            "[{}] Numeric key {key} of {:?} collides with {:?}",
+            type_name::<A>(),
+            id,
+            other
+        );
+    }
+}
+
+/// Logs and records two files producing the same ID.
+fn report_duplicate<Id, A>(
+    config: &FolderLoaderConfig<Id, A>,
+    folder_handle: &mut AssetFolderHandle<A>,
+    (id, locale): (&Id, Option<&str>),
+    first: &Path,
+    second: &Path,
+) where
+    Id: Clone + Eq + Hash + Send + Sync + Default + FromFilename + std::fmt::Debug + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
+{
+    let (kept, dropped) = match config.duplicate_policy {
+        DuplicatePolicy::KeepFirst => (first, second),
+        DuplicatePolicy::KeepLast | DuplicatePolicy::Warn => (second, first),
+    };
+    if config.duplicate_policy == DuplicatePolicy::Warn {
+        warn!(
+            "[{}] Duplicate asset ID {:?}: '{}' replaced by '{}'",
+            type_name::<A>(),
+            id,
+            dropped.display(),
+            kept.display()
+        );
+    } else {
+        debug!(
+            "[{}] Duplicate asset ID {:?}: keeping '{}' over '{}'",
+            type_name::<A>(),
+            id,
+            kept.display(),
+            dropped.display()
+        );
+    }
+    folder_handle.duplicates.push((
+        loaded_label(id, locale),
+        first.display().to_string(),
+        second.display().to_string(),
+    ));
+}
+
+/// Formats an ID (and locale variant, if any) for load reports.
+fn loaded_label<Id: std::fmt::Debug>(id: &Id, locale: Option<&str>) -> String {
+    match locale {
+        Some(locale) if !locale.is_empty() => format!("{id:?}.{locale}"),
+        _ => format!("{id:?}"),
+    }
+}
+
+/// Records a failed asset, unless the failure hook provides a substitute.
+fn fail_or_substitute<Id, A>(
+    config: &FolderLoaderConfig<Id, A>,
+    asset_server: &AssetServer,
+    library: &mut AssetFolder<Id, A>,
+    folder_handle: &mut AssetFolderHandle<A>,
+    (id, locale, path): (&Id, Option<&str>, &Path),
+    reason: String,
+) where
+    Id: Clone + Eq + Hash + Send + Sync + Default + FromFilename + std::fmt::Debug + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
+{
+    folder_handle.mark_progress();
+    let substitute = config
+        .on_failure
+        .and_then(|on_failure| on_failure(id.clone(), asset_server));
+    if let Some(substitute) = substitute {
+        warn!(
+            "[{}] Failed to load asset '{}', using substitute: {reason}",
+            type_name::<A>(),
+            path.display()
+        );
+        folder_handle.loaded_ids.push(loaded_label(id, locale));
+        register_entry(config, library, (id, locale, path), substitute);
+        return;
+    }
+
+    warn!(
+        "[{}] Failed to load asset '{}': {reason}",
+        type_name::<A>(),
+        path.display()
+    );
+    folder_handle
+        .failures
+        .push((path.display().to_string(), reason));
+}
+
+/// Logs and records that the configured folder doesn't exist.
+fn folder_not_found<A: Asset>(folder_path: &str, folder_handle: &mut AssetFolderHandle<A>) {
+    error!(
+        "[{}] Folder '{folder_path}' does not exist",
+        type_name::<A>()
+    );
+    folder_handle.folder_error = Some((
+        folder_path.to_string(),
+        FolderLoadError::FolderNotFound {
+            path: folder_path.to_string(),
+        },
+    ));
+}
+
+/// Recursively lists the files of folders on the default asset source.
+///
+/// See [`list_files`]. On failure, returns the configured folder being
+/// listed with the error.
+async fn list_folder(
+    asset_server: AssetServer,
+    folder_paths: Vec<&'static str>,
+    hidden_files: Vec<&'static str>,
+) -> ListedFiles {
+    let source = asset_server
+        .get_source(AssetSourceId::Default)
+        .map_err(|err| source_unavailable(folder_paths[0], &err))?;
+    list_files(source.reader(), folder_paths, hidden_files).await
+}
+
+/// The error for a path whose asset source is missing.
+fn source_unavailable(
+    path: &'static str,
+    err: &impl std::fmt::Display,
+) -> (&'static str, FolderLoadError) {
+    let err = FolderLoadError::SourceUnavailable {
+        path: path.to_string(),
+        reason: err.to_string(),
+    };
+    (path, err)
+}
+
+/// The error for a path that couldn't be read from the asset source.
+fn read_failed(path: &'static str, err: AssetReaderError) -> (&'static str, FolderLoadError) {
+    let err = match err {
+        AssetReaderError::NotFound(_) => FolderLoadError::FolderNotFound {
+            path: path.to_string(),
+        },
+        err => FolderLoadError::ParseFailed {
+            path: path.to_string(),
+            reason: err.to_string(),
+        },
+    };
+    (path, err)
+}
+
+/// Recursively lists the files of folders through an asset reader.
+///
+/// Files are listed folder by folder in the given order, sorted by path
+/// within each folder. Readers don't list dot-files, so each of
+/// `hidden_files` is looked up directly in every folder. On failure, returns
+/// the configured folder being listed with the error.
+async fn list_files(
+    reader: &dyn ErasedAssetReader,
+    folder_paths: Vec<&'static str>,
+    hidden_files: Vec<&'static str>,
+) -> ListedFiles {
+    let mut files = Vec::new();
+    for folder_path in folder_paths {
+        let start = files.len();
+        for file_name in &hidden_files {
+            let path = Path::new(folder_path).join(file_name);
+            if reader.read(&path).await.is_ok() {
+                files.push((path, None));
+            }
+        }
+        let mut folders = vec![PathBuf::from(folder_path)];
+        while let Some(folder) = folders.pop() {
+            let mut entries = reader
+                .read_directory(&folder)
+                .await
+                .map_err(|err| read_failed(folder_path, err))?;
+            while let Some(path) = entries.next().await {
+                if reader
+                    .is_directory(&path)
+                    .await
+                    .map_err(|err| read_failed(folder_path, err))?
+                {
+                    folders.push(path);
+                } else {
+                    files.push((path, None));
+                }
+            }
+        }
+        files[start..].sort();
+    }
+    Ok((files, Vec::new()))
+}
+
+/// An entry of a manifest, see [`FolderLoaderPlugin::with_manifest`].
+#[cfg(feature = "manifest")]
+#[derive(serde::Deserialize)]
+struct ManifestEntry {
+    id: String,
+    file: String,
+}
+
+/// Reads a manifest from the default asset source into its listed files.
+///
+/// Each file is resolved relative to `folder_path` and comes with its
+/// listed ID; files that don't exist are returned separately. On failure,
+/// returns the manifest path with the error.
+#[cfg(feature = "manifest")]
+async fn read_manifest(
+    asset_server: AssetServer,
+    manifest_path: &'static str,
+    folder_path: &'static str,
+) -> ListedFiles {
+    let source = asset_server
+        .get_source(AssetSourceId::Default)
+        .map_err(|err| source_unavailable(manifest_path, &err))?;
+    let mut reader = source
+        .reader()
+        .read(Path::new(manifest_path))
+        .await
+        .map_err(|err| read_failed(manifest_path, err))?;
+    let mut bytes = Vec::new();
+    reader
+        .read_to_end(&mut bytes)
+        .await
+        .map_err(|err| read_failed(manifest_path, AssetReaderError::Io(Arc::new(err))))?;
+    let entries: Vec<ManifestEntry> = ron::de::from_bytes(&bytes).map_err(|err| {
+        let err = FolderLoadError::InvalidManifest {
+            path: manifest_path.to_string(),
+            reason: err.to_string(),
+        };
+        (manifest_path, err)
+    })?;
+    let mut files = Vec::new();
+    let mut missing = Vec::new();
+    for entry in entries {
+        let path = Path::new(folder_path).join(entry.file);
+        // Other read errors are left for the asset server to report
+        let exists = !matches!(
+            source.reader().read(&path).await,
+            Err(AssetReaderError::NotFound(_))
+        );
+        if exists {
+            files.push((path, Some(entry.id)));
+        } else {
+            missing.push((path, entry.id));
+        }
+    }
+    Ok((files, missing))
+}
+
+/// Sorts the files of a folder into pending entries and skipped files.
+///
+/// Each file comes with its result of [`FolderLoaderConfig::classify`].
+/// `typed` produces the asset handle for a matching file, or `None` to start
+/// loading it later. Returns the files
+/// whose handles have the wrong asset type, with the reason.
+fn discover_folder<Id, A, T>(
+    config: &FolderLoaderConfig<Id, A>,
+    files: impl IntoIterator<Item = DiscoveredFile<Id, T>>,
+    mut typed: impl FnMut(T) -> Result<Option<Handle<A>>, String>,
+    folder_handle: &mut AssetFolderHandle<A>,
+    pending: &mut Vec<PendingAsset<Id, A>>,
+) -> Vec<(Id, Option<String>, PathBuf, String)>
+where
+    Id: Clone + Eq + Hash + Send + Sync + Default + FromFilename + std::fmt::Debug + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
+{
+    let mut mismatched = Vec::new();
+    let mut seen: HashMap<(Id, Option<String>), usize> = HashMap::new();
+    for (path, classified, file) in files {
+        // The folder defaults file is loaded separately
+        if let Some((file_name, _)) = config.folder_defaults
+            && path.file_name().is_some_and(|name| name == file_name)
+        {
+            continue;
+        }
+
+        // Files without an ID are skipped
+        let (id, locale) = match classified {
+            Ok(id) => id,
+            Err(reason) => {
+                config.skip(&path, reason);
+                folder_handle.skipped_paths.push((path.clone(), reason));
+                continue;
+            }
+        };
+
+        let handle = match typed(file) {
+            Ok(handle) => handle,
+            Err(reason) => {
+                mismatched.push((id, locale, path, reason));
+                continue;
+            }
+        };
+
+        // Resolve files sharing an ID according to the duplicate policy
+        let key = (id, locale);
+        if let Some(&index) = seen.get(&key) {
+            // The same file listed twice, e.g. by overlapping folders
+            let same_file = match (&pending[index].handle, &handle) {
+                (Some(first), Some(second)) => same_asset(first, second),
+                _ => pending[index].path == path,
+            };
+            if same_file {
+                continue;
+            }
+            let first = &pending[index].path;
+            report_duplicate(
+                config,
+                folder_handle,
+                (&key.0, key.1.as_deref()),
+                first,
+                &path,
+            );
+            if config.duplicate_policy != DuplicatePolicy::KeepFirst {
+                let (id, locale) = key;
+                pending[index] = PendingAsset {
+                    id,
+                    locale,
+                    path,
+                    handle,
+                };
+            }
+        } else {
+            seen.insert(key.clone(), pending.len());
+            let (id, locale) = key;
+            pending.push(PendingAsset {
+                id,
+                locale,
+                path,
+                handle,
+            });
+        }
+    }
+
+    if let Some(limit) = config.limit {
+        pending.sort_by(|a, b| a.path.cmp(&b.path));
+        for entry in pending.iter().skip(limit) {
+            config.skip(&entry.path, SkipReason::OverLimit);
+            folder_handle
+                .skipped_paths
+                .push((entry.path.clone(), SkipReason::OverLimit));
+        }
+        pending.truncate(limit);
+    }
+
+    folder_handle.total = pending.len();
+    folder_handle.discovered = true;
+    folder_handle.mark_progress();
+    mismatched
+}
+
+/// Discovers the files of resolved folders, failing those of another type.
+fn discover_typed<Id, A>(
+    config: &FolderLoaderConfig<Id, A>,
+    asset_server: &AssetServer,
+    files: impl IntoIterator<Item = DiscoveredFile<Id, UntypedHandle>>,
+    folder_handle: &mut AssetFolderHandle<A>,
+    pending: &mut Vec<PendingAsset<Id, A>>,
+    library: &mut AssetFolder<Id, A>,
+) where
+    Id: Clone + Eq + Hash + Send + Sync + Default + FromFilename + std::fmt::Debug + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
+{
+    let mismatched = discover_folder(
+        config,
+        files,
+        |handle| handle.try_typed().map(Some).map_err(|err| err.to_string()),
+        folder_handle,
+        pending,
+    );
+    for (id, locale, path, reason) in mismatched {
+        folder_handle
+            .type_mismatches
+            .push(path.display().to_string());
+        fail_or_substitute(
+            config,
+            asset_server,
+            library,
+            folder_handle,
+            (&id, locale.as_deref(), &path),
+            reason,
+        );
+    }
+}
+
+// =============================================================================
+// Blocking Loading
+// =============================================================================
+
+/// Loads a folder synchronously by updating `app` until it has resolved.
+///
+/// Meant for headless tests and asset-validation tools: `app` needs the
+/// `AssetPlugin` (e.g. with `MinimalPlugins`) and a loader for `A`, but no
+/// [`FolderLoaderPlugin`]. Every file is loaded through
+/// `AssetServer::load_folder`, IDs are derived with
+/// [`id_from_filename_with_extension`], and the returned library holds the
+/// handles of all matching files of type `A`, with their data available.
+///
+/// This takes the `App` rather than just the `AssetServer`: the server only
+/// queues loaded assets, and the app's asset systems have to run to store
+/// them in `Assets<A>` and update their load states, so the app is updated
+/// in a loop. A folder that never resolves (e.g. no loader registered for
+/// its files) would keep that loop spinning, hence the `timeout`.
+///
+/// # Errors
+///
+/// Returns [`FolderLoadError::ParseFailed`] if the folder fails to load,
+/// [`FolderLoadError::AssetFailed`] if one of its files does, and
+/// [`FolderLoadError::TimedOut`] if loading takes longer than `timeout`.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_folder_blocking<Id, A>(
+    app: &mut App,
+    folder_path: &str,
+    extension: &str,
+    timeout: Duration,
+) -> Result<AssetFolder<Id, A>, FolderLoadError>
+where
+    Id: Clone + Eq + Hash + Send + Sync + FromFilename + 'static,
+    A: Asset + Clone + Send + Sync + 'static,
+{
+    let deadline = std::time::Instant::now() + timeout;
+    let asset_server = app.world().resource::<AssetServer>().clone();
+    let folder = asset_server.load_folder(folder_path.to_string());
+
+    let mut library = AssetFolder::new();
+    let mut pending: Option<Vec<(Id, PathBuf, Handle<A>)>> = None;
+    loop {
+        app.update();
+        if pending.is_none() {
+            if let LoadState::Failed(err) = asset_server.load_state(&folder) {
+                let reason = err.to_string();
+                return Err(match &*err {
+                    // A single unloadable file fails the whole LoadedFolder
+                    AssetLoadError::AssetLoaderError(err) => FolderLoadError::AssetFailed {
+                        path: err.path().path().display().to_string(),
+                        reason,
+                    },
+                    _ => FolderLoadError::ParseFailed {
+                        path: folder_path.to_string(),
+                        reason,
+                    },
+                });
+            }
+            pending = app
+                .world()
+                .resource::<Assets<LoadedFolder>>()
+                .get(&folder)
+                .map(|loaded| {
+                    loaded
+                        .handles
+                        .iter()
+                        .filter_map(|handle| {
+                            let path = handle.path()?.path().to_path_buf();
+                            let id = id_from_filename_with_extension(&path, extension)?;
+                            Some((id, path, handle.clone().try_typed().ok()?))
+                        })
+                        .collect()
+                });
+        }
+        if let Some(entries) = &mut pending {
+            let assets = app.world().resource::<Assets<A>>();
+            let mut error = None;
+            entries.retain(|(id, path, handle)| {
+                if let LoadState::Failed(err) = asset_server.load_state(handle) {
+                    error.get_or_insert_with(|| FolderLoadError::AssetFailed {
+                        path: path.display().to_string(),
+                        reason: err.to_string(),
+                    });
+                    return false;
+                }
+                if !assets.contains(handle) {
+                    return true;
+                }
+                library.register(id.clone(), None, path, handle.clone());
+                false
+            });
+            if let Some(error) = error {
+                return Err(error);
+            }
+            if entries.is_empty() {
+                return Ok(library);
+            }
+        }
+        if std::time::Instant::now() >= deadline {
+            return Err(FolderLoadError::TimedOut {
+                path: folder_path.to_string(),
+            });
+        }
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
+
+// =============================================================================
+// ID Extraction Utilities
+// =============================================================================
+
+/// Extracts an ID from a filename by stripping the extension.
+///
+/// # Arguments
+///
+/// * `path` - The full path to the asset file
+/// * `extension` - The extension to strip (e.g., ".spell.ron")
+///
+/// # Returns
+///
+/// The ID if the filename matches the extension and is valid,
+/// or `None` if:
+/// - The file doesn't have the expected extension
+/// - The filename starts with `.` (hidden file)
+/// - The filename starts with `_` (disabled file)
+pub fn id_from_filename_with_extension<Id>(path: &Path, extension: &str) -> Option<Id>
+where
+    Id: FromFilename,
+{
+    id_from_filename_with_prefixes(path, extension, DEFAULT_SKIP_PREFIXES)
+}
+
+/// Builds an ID from the string the loader derived from a filename.
+///
+/// Implemented for every `From<String>` type, which costs an allocation per
+/// file. ID types that intern or hash the name can implement it directly to
+/// work from the borrowed string instead:
+///
+/// ```rust
+/// # use msg_load_folder::prelude::*;
+/// # use msg_load_folder::prelude::ids::InternedStrId;
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+/// struct SpellId(InternedStrId);
+///
+/// impl FromFilename for SpellId {
+///     fn from_filename(id: &str) -> Self {
+///         SpellId(InternedStrId::new(id))
+///     }
+/// }
+/// ```
+pub trait FromFilename {
+    /// Build the ID from the string derived from a filename.
+    fn from_filename(id: &str) -> Self;
+}
+
+impl<T: From<String>> FromFilename for T {
+    fn from_filename(id: &str) -> Self {
+        T::from(id.to_string())
+    }
+}
+
+/// Filename prefixes that mark hidden (`.`) and disabled (`_`) files.
+pub const DEFAULT_SKIP_PREFIXES: &[char] = &['.', '_'];
+
+/// Extracts an ID from a filename, skipping files starting with any of
+/// `skip_prefixes`.
+///
+/// [`id_from_filename_with_extension`] is this function with
+/// [`DEFAULT_SKIP_PREFIXES`]; see [`FolderLoaderPlugin::skip_prefixes`].
+pub fn id_from_filename_with_prefixes<Id>(
+    path: &Path,
+    extension: &str,
+    skip_prefixes: &[char],
+) -> Option<Id>
+where
+    Id: FromFilename,
+{
+    filename_id_str(path, extension, skip_prefixes).map(|id| Id::from_filename(&id))
+}
+
+/// The ID string of [`id_from_filename_with_prefixes`], borrowed from `path`
+/// if the filename is valid UTF-8.
+fn filename_id_str<'a>(
+    path: &'a Path,
+    extension: &str,
+    skip_prefixes: &[char],
+) -> Option<Cow<'a, str>> {
+    let filename = path.file_name()?.to_string_lossy();
+
+    // Check if filename has the expected extension
+    if !filename.ends_with(extension) {
+        return None;
+    }
+
+    // Strip extension to get the ID string
+    let id_len = filename.len() - extension.len();
+
+    // Skip hidden, disabled or otherwise prefixed files
+    if filename[..id_len].starts_with(skip_prefixes) {
+        return None;
+    }
+
+    // Skip empty IDs
+    if id_len == 0 {
+        return None;
+    }
+
+    Some(cow_slice(filename, 0..id_len))
+}
+
+/// Narrows a string to `range`, reusing its allocation if it is owned.
+fn cow_slice(text: Cow<'_, str>, range: std::ops::Range<usize>) -> Cow<'_, str> {
+    match text {
+        Cow::Borrowed(text) => Cow::Borrowed(&text[range]),
+        Cow::Owned(mut text) => {
+            text.truncate(range.end);
+            text.drain(..range.start);
+            Cow::Owned(text)
+        }
+    }
+}
+
+/// Extracts an ID from a filename matching any of several extensions.
+///
+/// The extensions are tried in order and the first one the filename ends
+/// with is stripped, as by [`id_from_filename_with_extension`], so list
+/// longer extensions before their suffixes (`".spell.ron"` before `".ron"`).
+pub fn id_from_filename_with_extensions<Id>(path: &Path, extensions: &[&str]) -> Option<Id>
+where
+    Id: FromFilename,
+{
+    let filename = path.file_name()?.to_string_lossy();
+    let extension = extensions
+        .iter()
+        .find(|extension| filename.ends_with(*extension))?;
+    id_from_filename_with_extension(path, extension)
+}
+
+/// Extracts an ID from a filename using the `id` capture group of a regex.
+///
+/// The file must still have the expected extension and not be hidden or
+/// disabled. The regex is matched against the full filename; `None` is
+/// returned if it doesn't match or the captured ID is empty.
+#[cfg(feature = "regex")]
+pub fn id_from_filename_with_regex<Id>(
+    path: &Path,
+    extension: &str,
+    regex: &regex::Regex,
+) -> Option<Id>
+where
+    Id: FromFilename,
+{
+    id_from_filename_with_regex_and_prefixes(path, extension, regex, DEFAULT_SKIP_PREFIXES)
+}
+
+/// Extracts an ID using the `id` capture group of a regex, skipping files
+/// starting with any of `skip_prefixes`.
+///
+/// [`id_from_filename_with_regex`] is this function with
+/// [`DEFAULT_SKIP_PREFIXES`]; see [`FolderLoaderPlugin::skip_prefixes`].
+#[cfg(feature = "regex")]
+pub fn id_from_filename_with_regex_and_prefixes<Id>(
+    path: &Path,
+    extension: &str,
+    regex: &regex::Regex,
+    skip_prefixes: &[char],
+) -> Option<Id>
+where
+    Id: FromFilename,
+{
+    regex_id_str(path, extension, regex, skip_prefixes).map(|id| Id::from_filename(&id))
+}
+
+/// The ID string of [`id_from_filename_with_regex_and_prefixes`], borrowed
+/// from `path` if the filename is valid UTF-8.
+#[cfg(feature = "regex")]
+fn regex_id_str<'a>(
+    path: &'a Path,
+    extension: &str,
+    regex: &regex::Regex,
+    skip_prefixes: &[char],
+) -> Option<Cow<'a, str>> {
+    let filename = path.file_name()?.to_string_lossy();
+    if !filename.ends_with(extension) || filename.starts_with(skip_prefixes) {
+        return None;
+    }
+
+    let id = regex.captures(&filename)?.name("id")?.range();
+    if id.is_empty() {
+        return None;
+    }
+
+    Some(cow_slice(filename, id))
+}
+
+/// Hashes an ID string into a stable `u64` key.
+///
+/// Uses 64-bit FNV-1a with its fixed offset basis, so the key for a given
+/// string is the same across runs, platforms and Rust versions.
+#[must_use]
+pub fn stable_id_hash(id: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    id.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+/// Legacy function for backwards compatibility.
+/// Extracts an ID from a filename using extension from path itself.
+pub fn id_from_filename<Id>(path: &Path, extension: &str) -> Option<Id>
+where
+    Id: FromFilename,
+{
+    id_from_filename_with_extension(path, extension)
+}
+
+/// Reconstructs the expected asset path for an ID.
+///
+/// This is the inverse of [`id_from_filename_with_extension`]: it joins the
+/// folder path, the ID and the extension, yielding a path relative to the
+/// asset source (e.g. `"prefabs/spells"`, `"fireball"`, `".spell.ron"` ->
+/// `"prefabs/spells/fireball.spell.ron"`). Nested IDs such as `fire/fireball`
+/// are joined as-is.
+#[must_use]
+pub fn resolve_asset_path(
+    folder_path: &str,
+    id: impl std::fmt::Display,
+    extension: &str,
+) -> String {
+    let folder = folder_path.trim_end_matches('/');
+    if folder.is_empty() {
+        format!("{id}{extension}")
+    } else {
+        format!("{folder}/{id}{extension}")
+    }
+}
+
+/// IDs compared across two folders by [`diff_folders`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FolderDiff {
+    /// IDs only present in the first folder, sorted.
+    pub only_in_first: Vec<String>,
+    /// IDs only present in the second folder, sorted.
+    pub only_in_second: Vec<String>,
+    /// IDs present in both folders, sorted.
+    pub in_both: Vec<String>,
+}
+
+/// Compares the IDs two folders on disk would produce, without loading them.
+///
+/// Lists and classifies both directory trees exactly like a
+/// [`FolderLoaderPlugin`] with default settings in two-pass mode
+/// (extension match, hidden and disabled files skipped). Useful for
+/// checking which entries a mod would override or add.
+///
+/// # Errors
+///
+/// Returns an error if either folder cannot be read.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn diff_folders(
+    first: &Path,
+    second: &Path,
+    extension: &'static str,
+) -> std::io::Result<FolderDiff> {
+    let config = FolderLoaderPlugin::<String, UnloadedAsset>::new("", extension).config();
+    let first = discover_ids(&config, first)?;
+    let second = discover_ids(&config, second)?;
+    Ok(FolderDiff {
+        only_in_first: first.difference(&second).cloned().collect(),
+        only_in_second: second.difference(&first).cloned().collect(),
+        in_both: first.intersection(&second).cloned().collect(),
+    })
+}
+
+/// Placeholder asset type for classifying files that are never loaded.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Asset, TypePath, Clone)]
+struct UnloadedAsset;
+
+/// Collects the IDs of every file below `path` that `config` would load.
+#[cfg(not(target_arch = "wasm32"))]
+fn discover_ids(
+    config: &FolderLoaderConfig<String, UnloadedAsset>,
+    path: &Path,
+) -> std::io::Result<std::collections::BTreeSet<String>> {
+    // Readers resolve relative roots against the base path, not the cwd
+    let root = std::env::current_dir()?.join(path);
+    let reader = bevy::asset::io::file::FileAssetReader::new(root);
+    let (files, _) = bevy::tasks::block_on(list_files(&reader, vec![""], config.empty_id_files()))
+        .map_err(|(_, err)| match err {
+            FolderLoadError::FolderNotFound { .. } => {
+                std::io::Error::new(std::io::ErrorKind::NotFound, err)
+            }
+            err => std::io::Error::other(err),
+        })?;
+    Ok(files
+        .into_iter()
+        .filter_map(|(path, _)| config.classify(&path).ok())
+        .map(|(id, _)| id)
+        .collect())
+}
+
+/// Returns the latest modification time of a directory and everything in it.
+#[cfg(not(target_arch = "wasm32"))]
+fn latest_modification(path: &Path) -> Option<SystemTime> {
+    let metadata = std::fs::metadata(path).ok()?;
+    let mut latest = metadata.modified().ok()?;
+    if metadata.is_dir() {
+        for entry in std::fs::read_dir(path).ok()?.flatten() {
+            if let Some(modified) = latest_modification(&entry.path()) {
+                latest = latest.max(modified);
+            }
+        }
+    }
+    Some(latest)
+}
+
+/// Check if a path represents a hidden or disabled file.
+#[must_use]
+pub fn is_hidden_file(path: &Path) -> bool {
+    path.file_name()
+        .map(|name| {
+            let name_str = name.to_string_lossy();
+            name_str.starts_with('.') || name_str.starts_with('_')
+        })
+        .unwrap_or(false)
+}
+
+/// Matches `text` against a glob pattern where `*` matches any run of characters.
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    // split always yields at least one item
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts: Vec<&str> = parts.collect();
+    let Some(last) = parts.pop() else {
+        // No `*` in the pattern: must be an exact match
+        return rest.is_empty();
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+// =============================================================================
+// Interned IDs
+// =============================================================================
+
+/// String ID interned for the lifetime of the program.
+///
+/// Equal strings are interned to the same `&'static str`, so the ID is
+/// `Copy` and cheap to clone, while equality, hashing and ordering are
+/// defined by the string content alone. Interned strings are never freed,
+/// so only use it for bounded sets such as content file names.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct InternedStrId(&'static str);
+
+impl InternedStrId {
+    /// Intern `id`, reusing the existing allocation for equal strings.
+    #[must_use]
+    pub fn new(id: &str) -> Self {
+        static INTERNED: std::sync::Mutex<Option<HashSet<&'static str>>> =
+            std::sync::Mutex::new(None);
+        let mut interned = INTERNED.lock().unwrap_or_else(|err| err.into_inner());
+        let interned = interned.get_or_insert_with(HashSet::new);
+        if let Some(existing) = interned.get(id) {
+            return Self(existing);
+        }
+        let leaked: &'static str = Box::leak(id.to_owned().into_boxed_str());
+        interned.insert(leaked);
+        Self(leaked)
+    }
+
+    /// The interned string.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl From<String> for InternedStrId {
+    fn from(id: String) -> Self {
+        Self::new(&id)
+    }
+}
+
+impl From<&str> for InternedStrId {
+    fn from(id: &str) -> Self {
+        Self::new(id)
+    }
+}
+
+impl std::fmt::Display for InternedStrId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+// =============================================================================
+// Handle Utilities
+// =============================================================================
+
+/// Check if two handles refer to the same asset.
+///
+/// Handles are compared by [`AssetId`] only, ignoring whether they are
+/// strong (owning) or UUID-based (non-owning, the successor of weak handles),
+/// and ignoring any held path or metadata. All handle comparisons in this
+/// crate go through this function.
+#[must_use]
+pub fn same_asset<A: Asset>(a: &Handle<A>, b: &Handle<A>) -> bool {
+    a.id() == b.id()
+}
+
+// =============================================================================
+// AtlasIcon
+// =============================================================================
+
+/// Icon rendering data from a texture atlas slice.
+///
+/// Contains all the handles and indices needed to render an icon from
+/// an atlas-based spritesheet. Requires the `ui` feature (enabled by default).
+#[cfg(feature = "ui")]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AtlasIcon {
+    /// The atlas image handle.
+    pub image: Handle<Image>,
+    /// The texture atlas layout handle.
+    pub layout: Handle<TextureAtlasLayout>,
+    /// The atlas index for this icon's slice.
+    pub atlas_index: usize,
+    /// Tint applied when rendering the icon (white by default).
+    pub tint: Color,
+}
+
+#[cfg(feature = "ui")]
+impl AtlasIcon {
+    /// Creates a new AtlasIcon.
+    #[must_use]
+    pub fn new(
+        image: Handle<Image>,
+        layout: Handle<TextureAtlasLayout>,
+        atlas_index: usize,
+    ) -> Self {
+        Self {
+            image,
+            layout,
+            atlas_index,
+            tint: Color::WHITE,
+        }
+    }
+
+    /// Creates an AtlasIcon for a named slice.
+    ///
+    /// Looks up the atlas index of `name` in `indices` (e.g. a name-to-index
+    /// map loaded alongside the layout), so content files can reference icons
+    /// by name. Returns `None` for unknown names.
+    #[must_use]
+    pub fn from_named(
+        image: Handle<Image>,
+        layout: Handle<TextureAtlasLayout>,
+        indices: &HashMap<String, usize>,
+        name: &str,
+    ) -> Option<Self> {
+        let atlas_index = *indices.get(name)?;
+        Some(Self::new(image, layout, atlas_index))
+    }
+
+    /// Creates an AtlasIcon for a slice of a loaded layout.
+    ///
+    /// Unlike [`Self::new`], the index is validated: returns `None` unless
+    /// `atlas_index` is below `layout_data.len()`, the number of slices of
+    /// the layout behind `layout`.
+    #[must_use]
+    pub fn from_grid(
+        image: Handle<Image>,
+        layout: Handle<TextureAtlasLayout>,
+        layout_data: &TextureAtlasLayout,
+        atlas_index: usize,
+    ) -> Option<Self> {
+        (atlas_index < layout_data.len()).then(|| Self::new(image, layout, atlas_index))
+    }
+
+    /// Returns this icon with the given tint color.
+    #[must_use]
+    pub fn with_tint(mut self, tint: Color) -> Self {
+        self.tint = tint;
+        self
+    }
+
+    /// Returns a clone of the underlying image handle for UI usage.
+    #[must_use]
+    pub fn get_image(&self) -> Handle<Image> {
+        self.image.clone()
+    }
+
+    /// Returns the texture atlas configuration for this icon.
+    #[must_use]
+    pub fn texture_atlas(&self) -> TextureAtlas {
+        TextureAtlas {
+            layout: self.layout.clone(),
+            index: self.atlas_index,
+        }
+    }
+
+    /// Creates an ImageNode from this icon, tinted with [`Self::tint`].
+    #[must_use]
+    pub fn image_node(&self) -> ImageNode {
+        ImageNode::from_atlas_image(self.image.clone(), self.texture_atlas()).with_color(self.tint)
+    }
+}
+
+/// Resource of atlas icons sharing one image and layout, indexed by ID.
+///
+/// Built from a name-to-index map (e.g. parsed from a RON file next to the
+/// spritesheet) with [`Self::from_indices`], which lets icon-heavy UIs look
+/// icons up by ID like an [`AssetFolder`]. Requires the `ui` feature.
+#[cfg(feature = "ui")]
+#[derive(Resource, Debug, Clone)]
+pub struct AtlasIconLibrary<Id>
+where
+    Id: Clone + Eq + Hash + Send + Sync + 'static,
+{
+    icons: HashMap<Id, AtlasIcon>,
+    rejected: Vec<(String, usize)>,
+}
+
+#[cfg(feature = "ui")]
+impl<Id> AtlasIconLibrary<Id>
+where
+    Id: Clone + Eq + Hash + Send + Sync + FromFilename + 'static,
+{
+    /// Builds icons for every entry of `indices` on a shared layout.
+    ///
+    /// Each index is validated against `layout_data.len()` as in
+    /// [`AtlasIcon::from_grid`]; out-of-range entries are logged as
+    /// warnings, left out and listed by [`Self::rejected`].
+    #[must_use]
+    pub fn from_indices(
+        image: &Handle<Image>,
+        layout: &Handle<TextureAtlasLayout>,
+        layout_data: &TextureAtlasLayout,
+        indices: &HashMap<String, usize>,
+    ) -> Self {
+        let mut icons = HashMap::with_capacity(indices.len());
+        let mut rejected = Vec::new();
+        for (name, &index) in indices {
+            match AtlasIcon::from_grid(image.clone(), layout.clone(), layout_data, index) {
+                Some(icon) => {
+                    icons.insert(Id::from_filename(name), icon);
+                }
+                None => {
+                    warn!(
+                        "Atlas icon '{name}' has index {index}, but the layout only has {} slices",
+                        layout_data.len()
+                    );
+                    rejected.push((name.clone(), index));
+                }
+            }
+        }
+        Self { icons, rejected }
+    }
+}
+
+#[cfg(feature = "ui")]
+impl<Id> AtlasIconLibrary<Id>
+where
+    Id: Clone + Eq + Hash + Send + Sync + 'static,
+{
+    /// Get the icon for an ID.
+    #[must_use]
+    pub fn get(&self, id: Id) -> Option<&AtlasIcon> {
+        self.icons.get(&id)
+    }
+
+    /// Check if the library contains an ID.
+    #[must_use]
+    pub fn contains(&self, id: Id) -> bool {
+        self.icons.contains_key(&id)
+    }
+
+    /// Returns the number of icons.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.icons.len()
+    }
+
+    /// Returns `true` if there are no icons.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.icons.is_empty()
+    }
+
+    /// Returns an iterator over all IDs and their icons.
+    pub fn iter(&self) -> impl Iterator<Item = (Id, &AtlasIcon)> + '_ {
+        self.icons.iter().map(|(id, icon)| (id.clone(), icon))
+    }
+
+    /// Names and indices left out for exceeding the layout's slice count.
+    #[must_use]
+    pub fn rejected(&self) -> &[(String, usize)] {
+        &self.rejected
+    }
+}
+
+/// Name-to-index map of a spritesheet, loaded from a `.atlas.ron` file by
+/// [`AtlasIconPlugin`].
+///
+/// The file holds a plain RON map, e.g. `{"sword": 0, "shield": 3}`.
+/// Requires the `atlas` feature.
+#[cfg(feature = "atlas")]
+#[derive(Asset, TypePath, Debug, Clone, Default, serde::Deserialize)]
+#[serde(transparent)]
+pub struct AtlasIconIndex(pub HashMap<String, usize>);
+
+/// Loads `.atlas.ron` files as [`AtlasIconIndex`].
+#[cfg(feature = "atlas")]
+#[derive(TypePath)]
+struct AtlasIconIndexLoader;
+
+#[cfg(feature = "atlas")]
+impl bevy::asset::AssetLoader for AtlasIconIndexLoader {
+    type Asset = AtlasIconIndex;
+    type Settings = ();
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+
+    async fn load(
+        &self,
+        reader: &mut dyn bevy::asset::io::Reader,
+        _settings: &(),
+        _load_context: &mut bevy::asset::LoadContext<'_>,
+    ) -> Result<AtlasIconIndex, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["atlas.ron"]
+    }
+}
+
+/// Plugin that builds an [`AtlasIconLibrary`] from a spritesheet and its
+/// RON index.
+///
+/// Loads the image and the [`AtlasIconIndex`] file, adds the layout to
+/// `Assets<TextureAtlasLayout>` and inserts the `AtlasIconLibrary<Id>`
+/// resource once the index has loaded. The library is rebuilt when the index
+/// changes on disk. Indices are validated against the layout as in
+/// [`AtlasIconLibrary::from_indices`]. Requires the `atlas` feature, and the
+/// `Image` and `TextureAtlasLayout` assets (registered by `ImagePlugin`).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use msg_load_folder::prelude::*;
+/// # fn build_app(app: &mut App) {
+/// let layout = TextureAtlasLayout::from_grid(UVec2::splat(32), 8, 8, None, None);
+/// app.add_plugins(AtlasIconPlugin::<String>::new(
+///     "ui/icons.png",
+///     "ui/icons.atlas.ron",
+///     layout,
+/// ));
+/// # }
+/// ```
+#[cfg(feature = "atlas")]
+pub struct AtlasIconPlugin<Id> {
+    image_path: &'static str,
+    index_path: &'static str,
+    layout: TextureAtlasLayout,
+    _marker: PhantomData<fn() -> Id>,
+}
+
+#[cfg(feature = "atlas")]
+impl<Id> AtlasIconPlugin<Id> {
+    /// Creates a plugin for the spritesheet at `image_path`, sliced by
+    /// `layout`, with the names of its slices in the `.atlas.ron` file at
+    /// `index_path`.
+    #[must_use]
+    pub fn new(
+        image_path: &'static str,
+        index_path: &'static str,
+        layout: TextureAtlasLayout,
+    ) -> Self {
+        Self {
+            image_path,
+            index_path,
+            layout,
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "atlas")]
+impl<Id> Plugin for AtlasIconPlugin<Id>
+where
+    Id: Clone + Eq + Hash + Send + Sync + FromFilename + 'static,
+{
+    fn build(&self, app: &mut App) {
+        if !app.world().contains_resource::<Assets<AtlasIconIndex>>() {
+            app.init_asset::<AtlasIconIndex>()
+                .register_asset_loader(AtlasIconIndexLoader);
+        }
+        app.insert_resource(AtlasIconSource::<Id> {
+            image_path: self.image_path,
+            index_path: self.index_path,
+            layout: self.layout.clone(),
+            handles: None,
+            _marker: PhantomData,
+        });
+        app.add_systems(Update, build_atlas_icons::<Id>);
+    }
+}
+
+/// The spritesheet an [`AtlasIconPlugin`] builds its library from.
+#[cfg(feature = "atlas")]
+#[derive(Resource)]
+struct AtlasIconSource<Id> {
+    image_path: &'static str,
+    index_path: &'static str,
+    layout: TextureAtlasLayout,
+    /// Image, layout and index handles, set on the first run.
+    handles: Option<(
+        Handle<Image>,
+        Handle<TextureAtlasLayout>,
+        Handle<AtlasIconIndex>,
+    )>,
+    _marker: PhantomData<fn() -> Id>,
+}
+
+/// Starts loading the spritesheet and (re)builds the icon library whenever
+/// its index loads.
+#[cfg(feature = "atlas")]
+fn build_atlas_icons<Id>(
+    mut commands: Commands,
+    mut source: ResMut<AtlasIconSource<Id>>,
+    asset_server: Res<AssetServer>,
+    mut layouts: ResMut<Assets<TextureAtlasLayout>>,
+    indices: Res<Assets<AtlasIconIndex>>,
+    mut events: MessageReader<AssetEvent<AtlasIconIndex>>,
+) where
+    Id: Clone + Eq + Hash + Send + Sync + FromFilename + 'static,
+{
+    let source = &mut *source;
+    let (image, layout, index) = source.handles.get_or_insert_with(|| {
+        (
+            asset_server.load(source.image_path),
+            layouts.add(source.layout.clone()),
+            asset_server.load(source.index_path),
+        )
+    });
+    let loaded = events.read().any(|event| match event {
+        AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => {
+            *id == index.id()
+        }
+        _ => false,
+    });
+    if !loaded {
+        return;
+    }
+    let (Some(layout_data), Some(index_data)) = (layouts.get(&*layout), indices.get(&*index))
+    else {
+        return;
+    };
+    commands.insert_resource(AtlasIconLibrary::<Id>::from_indices(
+        image,
+        layout,
+        layout_data,
+        &index_data.0,
+    ));
+}
+
+// =============================================================================
+// Parsing Utilities
+// =============================================================================
+
+/// Deserializes a string field to `Option<String>`.
+/// Accepts a bare string and converts empty strings to `None`.
+///
+/// # Example
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use msg_load_folder::deserialize_optional_string;
+///
+/// #[derive(Deserialize)]
+/// struct MyData {
+///     #[serde(default, deserialize_with = "deserialize_optional_string")]
+///     atlas_slice: Option<String>,
+/// }
+/// ```
+pub fn deserialize_optional_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::Deserialize;
+    let s = String::deserialize(deserializer)?;
+    Ok(if s.is_empty() { None } else { Some(s) })
+}
+
+/// Deserializes a number field to `Option<f32>`.
+///
+/// Converts `0.0` to `None`; combine with `#[serde(default)]` so an absent
+/// field is `None` as well.
+///
+/// # Example
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use msg_load_folder::deserialize_optional_f32;
+///
+/// #[derive(Deserialize)]
+/// struct MyData {
+///     #[serde(default, deserialize_with = "deserialize_optional_f32")]
+///     cooldown: Option<f32>,
+/// }
+/// ```
+pub fn deserialize_optional_f32<'de, D>(deserializer: D) -> Result<Option<f32>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::Deserialize;
+    let value = f32::deserialize(deserializer)?;
+    Ok(if value == 0.0 { None } else { Some(value) })
+}
+
+/// Deserializes an array field to a `Vec`, rejecting empty arrays.
+///
+/// # Example
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use msg_load_folder::deserialize_nonempty_vec;
+///
+/// #[derive(Deserialize)]
+/// struct MyData {
+///     #[serde(deserialize_with = "deserialize_nonempty_vec")]
+///     tags: Vec<String>,
+/// }
+/// ```
+pub fn deserialize_nonempty_vec<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    use serde::Deserialize;
+    use serde::de::Error;
+    let values = Vec::<T>::deserialize(deserializer)?;
+    if values.is_empty() {
+        return Err(D::Error::custom("expected a non-empty array"));
+    }
+    Ok(values)
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Mock ID type for testing
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+    struct MockId(u64);
+
+    impl From<String> for MockId {
+        fn from(s: String) -> Self {
+            MockId(s.len() as u64)
+        }
+    }
+
+    #[test]
+    fn test_id_from_filename_valid() {
+        let path = Path::new("test_item.mock.ron");
+        let id: Option<MockId> = id_from_filename_with_extension(path, ".mock.ron");
+        assert!(id.is_some());
+    }
+
+    #[test]
+    fn test_id_from_filename_hidden() {
+        let path = Path::new(".hidden.mock.ron");
+        let id: Option<MockId> = id_from_filename_with_extension(path, ".mock.ron");
+        assert!(id.is_none());
+    }
+
+    #[test]
+    fn test_id_from_filename_disabled() {
+        let path = Path::new("_disabled.mock.ron");
+        let id: Option<MockId> = id_from_filename_with_extension(path, ".mock.ron");
+        assert!(id.is_none());
+    }
+
+    #[test]
+    fn test_id_from_filename_wrong_extension() {
+        let path = Path::new("test_item.other.ron");
+        let id: Option<MockId> = id_from_filename_with_extension(path, ".mock.ron");
+        assert!(id.is_none());
+    }
+
+    #[test]
+    fn test_is_hidden_file() {
+        assert!(is_hidden_file(Path::new(".hidden.ron")));
+        assert!(is_hidden_file(Path::new("_disabled.ron")));
+        assert!(!is_hidden_file(Path::new("normal.ron")));
+    }
+
+    #[test]
+    fn test_asset_folder_handle_states() {
+        // Mock asset type for testing
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let mut handle: AssetFolderHandle<MockAsset> = AssetFolderHandle::new();
+
+        // Initial state
+        assert!(!handle.is_loaded());
+
+        // After starting load
+        handle.handle = Some(Handle::default());
+        assert!(!handle.is_loaded());
+
+        // After processing complete
+        handle.processed = true;
+        assert!(handle.is_loaded());
+    }
+
+    #[test]
+    fn test_folder_asset_library() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let mut library: AssetFolder<MockId, MockAsset> = AssetFolder::new();
+
+        assert!(library.is_empty());
+        assert_eq!(library.len(), 0);
+        assert!(!library.is_ready());
+
+        let id = MockId(1);
+        library.insert(id, Handle::default());
+
+        assert!(!library.is_empty());
+        assert_eq!(library.len(), 1);
+        assert!(library.is_ready());
+        assert!(library.contains(id));
+        assert!(library.get(id).is_some());
+
+        let keys: Vec<_> = library.keys().collect();
+        assert_eq!(keys.len(), 1);
+
+        let iter_count = library.iter().count();
+        assert_eq!(iter_count, 1);
+    }
+
+    #[test]
+    fn test_same_asset_compares_by_asset_id() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let mut assets = Assets::<MockAsset>::default();
+        let strong = assets.add(MockAsset);
+        let other = assets.add(MockAsset);
+        assert!(strong.is_strong());
+
+        // Non-owning handles built separately for the same id compare equal
+        let uuid = bevy::asset::uuid::Uuid::from_u128(42);
+        let by_uuid: Handle<MockAsset> = Handle::from(uuid);
+        assert!(!by_uuid.is_strong());
+        assert!(same_asset(&by_uuid, &Handle::Uuid(uuid, PhantomData)));
+
+        assert!(same_asset(&strong, &strong.clone()));
+        assert!(!same_asset(&strong, &other));
+        assert!(!same_asset(&strong, &by_uuid));
+    }
+
+    #[test]
+    #[cfg(feature = "ui")]
+    fn test_atlas_icon() {
+        let icon = AtlasIcon::new(Handle::default(), Handle::default(), 5);
+
+        assert_eq!(icon.atlas_index, 5);
+
+        let atlas = icon.texture_atlas();
+        assert_eq!(atlas.index, 5);
+    }
+
+    // ==========================================================================
+    // Additional tests for Bevy 0.17 migration validation
+    // ==========================================================================
+
+    #[test]
+    fn test_id_from_filename_extracts_correct_id() {
+        let path = Path::new("fireball.spell.ron");
+        let id: Option<MockId> = id_from_filename_with_extension(path, ".spell.ron");
+        assert!(id.is_some());
+        // "fireball" has 8 characters
+        assert_eq!(id.unwrap(), MockId(8));
+    }
+
+    #[test]
+    fn test_id_from_filename_with_nested_path() {
+        let path = Path::new("prefabs/spells/fireball.spell.ron");
+        let id: Option<MockId> = id_from_filename_with_extension(path, ".spell.ron");
+        assert!(id.is_some());
+        assert_eq!(id.unwrap(), MockId(8)); // "fireball"
+    }
+
+    #[test]
+    fn test_id_from_filename_empty_id() {
+        // Extension only - should return None
+        let path = Path::new(".spell.ron");
+        let id: Option<MockId> = id_from_filename_with_extension(path, ".spell.ron");
+        assert!(id.is_none());
+    }
+
+    #[test]
+    fn test_id_from_filename_with_extensions() {
+        let extensions = [".spell.ron", ".spell.json"];
+        let ron: Option<String> =
+            id_from_filename_with_extensions(Path::new("spells/fireball.spell.ron"), &extensions);
+        assert_eq!(ron.as_deref(), Some("fireball"));
+        let json: Option<String> =
+            id_from_filename_with_extensions(Path::new("spells/heal.spell.json"), &extensions);
+        assert_eq!(json.as_deref(), Some("heal"));
+        let other: Option<String> =
+            id_from_filename_with_extensions(Path::new("spells/blink.item.ron"), &extensions);
+        assert_eq!(other, None);
+        // The first matching extension wins
+        let short: Option<String> = id_from_filename_with_extensions(
+            Path::new("fireball.spell.ron"),
+            &[".ron", ".spell.ron"],
+        );
+        assert_eq!(short.as_deref(), Some("fireball.spell"));
+    }
+
+    #[test]
+    fn test_legacy_id_from_filename() {
+        let path = Path::new("test_item.mock.ron");
+        let id: Option<MockId> = id_from_filename(path, ".mock.ron");
+        assert!(id.is_some());
+        assert_eq!(id.unwrap(), MockId(9)); // "test_item"
+    }
+
+    #[test]
+    fn test_resolve_asset_path_round_trip() {
+        let original = "prefabs/spells/fireball.spell.ron";
+        let id: String =
+            id_from_filename_with_extension(Path::new(original), ".spell.ron").unwrap();
+        assert_eq!(id, "fireball");
+        assert_eq!(
+            resolve_asset_path("prefabs/spells", &id, ".spell.ron"),
+            original
+        );
+        assert_eq!(
+            resolve_asset_path("prefabs/spells/", &id, ".spell.ron"),
+            original
+        );
+        assert_eq!(
+            resolve_asset_path("", &id, ".spell.ron"),
+            "fireball.spell.ron"
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_id_from_filename_with_regex() {
+        let regex = regex::Regex::new(r"^spell_(?P<id>\d+)\.ron$").unwrap();
+
+        let id: Option<String> =
+            id_from_filename_with_regex(Path::new("spells/spell_0042.ron"), ".ron", &regex);
+        assert_eq!(id.as_deref(), Some("0042"));
+
+        let id: Option<String> =
+            id_from_filename_with_regex(Path::new("spells/fireball.ron"), ".ron", &regex);
+        assert!(id.is_none());
+
+        let id: Option<String> =
+            id_from_filename_with_regex(Path::new("spells/_spell_0001.ron"), ".ron", &regex);
+        assert!(id.is_none());
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    #[should_panic(expected = "no capture group named `id`")]
+    fn test_with_id_regex_requires_id_group() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let _ = FolderLoaderPlugin::<MockId, MockAsset>::new("spells", ".ron")
+            .with_id_regex(r"^spell_(\d+)\.ron$");
+    }
+
+    #[test]
+    fn test_is_hidden_file_with_nested_paths() {
+        assert!(is_hidden_file(Path::new("some/path/.hidden.ron")));
+        assert!(is_hidden_file(Path::new("some/path/_disabled.ron")));
+        assert!(!is_hidden_file(Path::new("some/path/normal.ron")));
+    }
+
+    #[test]
+    fn test_asset_folder_multiple_assets() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let mut library: AssetFolder<MockId, MockAsset> = AssetFolder::new();
+
+        // Insert multiple assets
+        for i in 0..10 {
+            library.insert(MockId(i), Handle::default());
+        }
+
+        assert_eq!(library.len(), 10);
+        assert!(library.is_ready());
+
+        // Verify all are accessible
+        for i in 0..10 {
+            assert!(library.contains(MockId(i)));
+            assert!(library.get(MockId(i)).is_some());
+        }
+
+        // Test keys count
+        let keys: Vec<_> = library.keys().collect();
+        assert_eq!(keys.len(), 10);
+
+        // Test iteration
+        let iter_count = library.iter().count();
+        assert_eq!(iter_count, 10);
+    }
+
+    #[test]
+    fn test_asset_folder_get_mut() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let mut library: AssetFolder<MockId, MockAsset> = AssetFolder::new();
+        let id = MockId(1);
+        library.insert(id, Handle::default());
+
+        // Test mutable access
+        assert!(library.get_mut(id).is_some());
+        assert!(library.get_mut(MockId(999)).is_none());
+    }
+
+    #[test]
+    fn test_asset_folder_insert_returns_old_value() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let mut library: AssetFolder<MockId, MockAsset> = AssetFolder::new();
+        let id = MockId(1);
+
+        // First insert returns None
+        let old = library.insert(id, Handle::default());
+        assert!(old.is_none());
+
+        // Second insert returns the old handle
+        let old = library.insert(id, Handle::default());
+        assert!(old.is_some());
+    }
+
+    #[test]
+    fn test_asset_folder_deref() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let mut library: AssetFolder<MockId, MockAsset> = AssetFolder::new();
+        library.insert(MockId(1), Handle::default());
+
+        // Test Deref access to HashMap methods
+        assert!(library.contains_key(&MockId(1)));
+        assert!(!library.contains_key(&MockId(2)));
+    }
+
+
+    #[test]
+    #[cfg(feature = "ui")]
+    fn test_atlas_icon_image_node_creation() {
+        let icon = AtlasIcon::new(Handle::default(), Handle::default(), 3);
+
+        // Test that image_node() creates a valid ImageNode
+        let _image_node = icon.image_node();
+
+        // Test get_image returns a handle
+        let _image = icon.get_image();
+    }
+
+    #[test]
+    fn test_is_stalled_after_threshold_without_progress() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let mut folder_handle: AssetFolderHandle<MockAsset> = AssetFolderHandle::new();
+        assert!(!folder_handle.is_stalled(Duration::ZERO));
+
+        folder_handle.tick(Duration::from_secs(1));
+        folder_handle.discovered = true;
+        folder_handle.mark_progress();
+        folder_handle.tick(Duration::from_secs(4));
+        assert!(!folder_handle.is_stalled(Duration::from_secs(5)));
+
+        // Counts stop changing
+        folder_handle.tick(Duration::from_secs(6));
+        assert!(folder_handle.is_stalled(Duration::from_secs(5)));
+
+        folder_handle.mark_progress();
+        assert!(!folder_handle.is_stalled(Duration::from_secs(5)));
+
+        folder_handle.tick(Duration::from_secs(20));
+        folder_handle.processed = true;
+        assert!(!folder_handle.is_stalled(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_interned_str_id_compares_by_content() {
+        use std::hash::{BuildHasher, RandomState};
+
+        let first = InternedStrId::from(String::from("fireball"));
+        let second = InternedStrId::from(format!("fire{}", "ball"));
+        assert_eq!(first, second);
+        assert!(std::ptr::eq(first.as_str(), second.as_str()));
+
+        let hasher = RandomState::new();
+        assert_eq!(hasher.hash_one(first), hasher.hash_one(second));
+
+        let mut ids = [
+            InternedStrId::new("heal"),
+            first,
+            InternedStrId::new("bolt"),
+        ];
+        ids.sort();
+        assert_eq!(
+            ids.iter().map(InternedStrId::as_str).collect::<Vec<_>>(),
+            vec!["bolt", "fireball", "heal"]
+        );
+        assert_eq!(InternedStrId::default().as_str(), "");
+    }
+
+    #[test]
+    #[cfg(feature = "ui")]
+    fn test_atlas_icon_from_named() {
+        let indices = HashMap::from([("sword".to_string(), 4), ("shield".to_string(), 7)]);
+
+        let icon = AtlasIcon::from_named(Handle::default(), Handle::default(), &indices, "shield")
+            .unwrap();
+        assert_eq!(icon.atlas_index, 7);
+        assert_eq!(icon.texture_atlas().index, 7);
+
+        assert!(
+            AtlasIcon::from_named(Handle::default(), Handle::default(), &indices, "bow").is_none()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "ui")]
+    fn test_atlas_icon_library_validates_indices() {
+        let layout_data = TextureAtlasLayout::from_grid(UVec2::splat(16), 4, 1, None, None);
+        assert!(
+            AtlasIcon::from_grid(Handle::default(), Handle::default(), &layout_data, 3).is_some()
+        );
+        assert!(
+            AtlasIcon::from_grid(Handle::default(), Handle::default(), &layout_data, 4).is_none()
+        );
+
+        let indices = HashMap::from([
+            ("sword".to_string(), 0),
+            ("shield".to_string(), 3),
+            ("bow".to_string(), 7),
+        ]);
+        let library: AtlasIconLibrary<String> = AtlasIconLibrary::from_indices(
+            &Handle::default(),
+            &Handle::default(),
+            &layout_data,
+            &indices,
+        );
+        assert_eq!(library.len(), 2);
+        assert_eq!(
+            library
+                .get("shield".to_string())
+                .map(|icon| icon.atlas_index),
+            Some(3)
+        );
+        assert!(!library.contains("bow".to_string()));
+        assert_eq!(library.rejected(), &[("bow".to_string(), 7)]);
+    }
+
+    #[test]
+    #[cfg(feature = "atlas")]
+    fn test_atlas_icon_plugin_builds_library_from_index() {
+        let root = temp_assets(&[(
+            "ui/icons.atlas.ron",
+            "{\"sword\": 0, \"shield\": 3, \"bow\": 7}",
+        )]);
+        let mut app = test_app(&root);
+        app.init_asset::<Image>()
+            .init_asset::<TextureAtlasLayout>()
+            .add_plugins(AtlasIconPlugin::<String>::new(
+                "ui/icons.png",
+                "ui/icons.atlas.ron",
+                TextureAtlasLayout::from_grid(UVec2::splat(16), 4, 1, None, None),
+            ));
+        update_until(&mut app, |app| {
+            app.world().contains_resource::<AtlasIconLibrary<String>>()
+        });
+
+        let library = app.world().resource::<AtlasIconLibrary<String>>();
+        assert_eq!(library.len(), 2);
+        assert_eq!(
+            library
+                .get("shield".to_string())
+                .map(|icon| icon.atlas_index),
+            Some(3)
+        );
+        assert_eq!(library.rejected(), &[("bow".to_string(), 7)]);
+    }
+
+    #[test]
+    #[cfg(feature = "ui")]
+    fn test_atlas_icon_default() {
+        let icon = AtlasIcon::default();
+
+        assert_eq!(icon.atlas_index, 0);
+        assert_eq!(icon.tint, Color::WHITE);
+    }
+
+    #[test]
+    #[cfg(feature = "ui")]
+    fn test_atlas_icon_with_tint() {
+        let icon = AtlasIcon::new(Handle::default(), Handle::default(), 2);
+        assert_eq!(icon.tint, Color::WHITE);
+
+        let tint = Color::srgb(1.0, 0.5, 0.25);
+        let icon = icon.with_tint(tint);
+        assert_eq!(icon.tint, tint);
+        assert_eq!(icon.image_node().color, tint);
+    }
+
+    #[test]
+    #[cfg(feature = "ui")]
+    fn test_atlas_icon_equality() {
+        let icon1 = AtlasIcon::new(Handle::default(), Handle::default(), 5);
+        let _icon2 = AtlasIcon::new(Handle::default(), Handle::default(), 5);
+        let icon3 = AtlasIcon::new(Handle::default(), Handle::default(), 3);
+
+        // Note: Handle::default() creates different handles each time,
+        // so icon1 == icon2 may be false depending on implementation
+        // But icon should not equal one with different index
+        assert_ne!(icon1.atlas_index, icon3.atlas_index);
+    }
+
+    #[test]
+    fn test_asset_folder_handle_default() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let handle: AssetFolderHandle<MockAsset> = AssetFolderHandle::default();
+
+        assert!(!handle.is_loaded());
+        assert!(handle.handle.is_none());
+    }
+
+    #[test]
+    fn test_asset_folder_default() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let library: AssetFolder<MockId, MockAsset> = AssetFolder::default();
+
+        assert!(library.is_empty());
+        assert!(!library.is_ready());
+    }
+
+    #[test]
+    fn test_asset_folder_assets_access() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let mut library: AssetFolder<MockId, MockAsset> = AssetFolder::new();
+        library.insert(MockId(1), Handle::default());
+
+        // Test direct HashMap access
+        let assets = library.assets();
+        assert_eq!(assets.len(), 1);
+
+        let assets_mut = library.assets_mut();
+        assets_mut.insert(MockId(2), Handle::default());
+        assert_eq!(library.len(), 2);
+    }
+
+    #[test]
+    fn test_asset_folder_iter_mut() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let mut library: AssetFolder<MockId, MockAsset> = AssetFolder::new();
+        library.insert(MockId(1), Handle::default());
+        library.insert(MockId(2), Handle::default());
+
+        // Test mutable iteration
+        let count = library.iter_mut().count();
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn test_folder_became_ready_fires_once() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        #[derive(Resource, Default)]
+        struct Runs(usize);
+
+        let mut app = App::new();
+        app.init_resource::<AssetFolder<MockId, MockAsset>>()
+            .init_resource::<Runs>()
+            .add_systems(
+                Update,
+                (|mut runs: ResMut<Runs>| runs.0 += 1)
+                    .run_if(folder_became_ready::<MockId, MockAsset>),
+            );
+
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<Runs>().0, 0);
+
+        app.world_mut()
+            .resource_mut::<AssetFolder<MockId, MockAsset>>()
+            .insert(MockId(1), Handle::default());
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<Runs>().0, 1);
+    }
+
+    #[test]
+    fn test_asset_folder_ready_at_count() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let mut library: AssetFolder<MockId, MockAsset> = AssetFolder::new();
+        library.set_ready_at_count(3);
+
+        library.insert(MockId(1), Handle::default());
+        library.insert(MockId(2), Handle::default());
+        assert!(!library.is_ready());
+
+        library.insert(MockId(3), Handle::default());
+        assert!(library.is_ready());
+
+        // Zero behaves like the default threshold
+        let mut library: AssetFolder<MockId, MockAsset> = AssetFolder::new();
+        library.set_ready_at_count(0);
+        assert!(!library.is_ready());
+    }
+
+    #[test]
+    fn test_asset_folder_set_disabled() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let mut library: AssetFolder<MockId, MockAsset> = AssetFolder::new();
+        library.insert(MockId(1), Handle::default());
+        library.insert(MockId(2), Handle::default());
+
+        library.set_disabled(MockId(1), true);
+        assert!(library.is_disabled(MockId(1)));
+        assert!(!library.contains(MockId(1)));
+        assert!(library.get(MockId(1)).is_none());
+        assert!(library.get_including_disabled(MockId(1)).is_some());
+        assert_eq!(
+            library.iter().map(|(id, _)| id).collect::<Vec<_>>(),
+            vec![MockId(2)]
+        );
+        assert_eq!(library.keys().count(), 1);
+        assert_eq!(library.len(), 1);
+
+        library.set_disabled(MockId(2), true);
+        assert!(library.is_empty());
+        assert!(!library.is_ready());
+
+        library.set_disabled(MockId(1), false);
+        library.set_disabled(MockId(2), false);
+        assert!(library.contains(MockId(1)));
+        assert_eq!(library.iter().count(), 2);
+    }
+
+    #[test]
+    fn test_asset_folder_keys_ref() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let mut library: AssetFolder<MockId, MockAsset> = AssetFolder::new();
+        library.insert(MockId(1), Handle::default());
+        library.insert(MockId(2), Handle::default());
+        library.set_disabled(MockId(2), true);
+
+        let keys: Vec<&MockId> = library.keys_ref().collect();
+        assert_eq!(keys, vec![&MockId(1)]);
+    }
+
+    #[test]
+    fn test_asset_folder_non_copy_id() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let mut library: AssetFolder<std::sync::Arc<str>, MockAsset> = AssetFolder::new();
+        let fireball: std::sync::Arc<str> = "fireball".into();
+        library.insert(fireball.clone(), Handle::default());
+        library.insert("ice_shard".into(), Handle::default());
+
+        assert!(library.contains(fireball.clone()));
+        assert!(library.get("ice_shard".into()).is_some());
+        library.set_disabled(fireball.clone(), true);
+        assert_eq!(library.keys().collect::<Vec<_>>(), vec!["ice_shard".into()]);
+        assert_eq!(library.find_matching("ice*").len(), 1);
+    }
+
+    #[test]
+    fn test_asset_folder_snapshot() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let mut assets = Assets::<MockAsset>::default();
+        let mut library: AssetFolder<&'static str, MockAsset> = AssetFolder::new();
+        for id in ["heal", "fireball", "ice_shard"] {
+            library.insert(id, assets.add(MockAsset));
+        }
+
+        let snapshot = library.snapshot();
+        assert_eq!(snapshot.len(), library.len());
+        for (id, handle) in &snapshot {
+            assert_eq!(library.get(id), Some(handle));
+        }
+
+        let ids: Vec<_> = library
+            .snapshot_sorted()
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(ids, vec!["fireball", "heal", "ice_shard"]);
+
+        // The snapshot is independent of later changes
+        library.insert("frost", Handle::default());
+        assert_eq!(snapshot.len(), 3);
+    }
+
+    #[test]
+    fn test_iter_by_sort_key_orders_by_field_then_id() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset {
+            sort_order: i64,
+        }
+
+        let mut assets = Assets::<MockAsset>::default();
+        let mut library: AssetFolder<&'static str, MockAsset> = AssetFolder::new();
+        for (id, sort_order) in [("heal", 2), ("fireball", 5), ("ice_shard", 2), ("bolt", -1)] {
+            library.insert(id, assets.add(MockAsset { sort_order }));
+        }
+        library.insert("unloaded", Handle::default());
+
+        // Without a key function the order is by ID
+        let ids: Vec<_> = library
+            .iter_by_sort_key(&assets)
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(
+            ids,
+            vec!["bolt", "fireball", "heal", "ice_shard", "unloaded"]
+        );
+
+        library.set_sort_key_fn(|asset| asset.sort_order);
+        let ids: Vec<_> = library
+            .iter_by_sort_key(&assets)
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(
+            ids,
+            vec!["bolt", "heal", "ice_shard", "fireball", "unloaded"]
+        );
+    }
+
+    #[test]
+    fn test_get_data_and_iter_data() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset {
+            damage: i64,
+        }
+
+        let mut assets = Assets::<MockAsset>::default();
+        let mut library: AssetFolder<&'static str, MockAsset> = AssetFolder::new();
+        library.insert("fireball", assets.add(MockAsset { damage: 50 }));
+        library.insert("heal", assets.add(MockAsset { damage: 0 }));
+        library.insert("unloaded", Handle::default());
+
+        assert_eq!(
+            library.get_data("fireball", &assets).map(|a| a.damage),
+            Some(50)
+        );
+        assert!(library.get_data("unloaded", &assets).is_none());
+        assert!(library.get_data("missing", &assets).is_none());
+
+        library.set_disabled("heal", true);
+        assert!(library.get_data("heal", &assets).is_none());
+        let data: Vec<_> = library
+            .iter_data(&assets)
+            .map(|(id, asset)| (id, asset.damage))
+            .collect();
+        assert_eq!(data, vec![("fireball", 50)]);
+
+        let loaded: Vec<_> = library
+            .iter_loaded(&assets)
+            .map(|(id, handle, asset)| (id, handle.clone(), asset.damage))
+            .collect();
+        assert_eq!(loaded, vec![("fireball", library["fireball"].clone(), 50)]);
+    }
+
+    #[test]
+    fn test_get_many() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let mut assets = Assets::<MockAsset>::default();
+        let mut library: AssetFolder<&'static str, MockAsset> = AssetFolder::new();
+        let sword = assets.add(MockAsset);
+        let shield = assets.add(MockAsset);
+        library.insert("sword", sword.clone());
+        library.insert("shield", shield.clone());
+        library.insert("bow", assets.add(MockAsset));
+        library.set_disabled("bow", true);
+
+        let [a, b, c, d] = library.get_many(["shield", "missing", "sword", "bow"]);
+        assert_eq!(a, Some(&shield));
+        assert_eq!(b, None);
+        assert_eq!(c, Some(&sword));
+        assert_eq!(d, None);
+    }
+
+    #[test]
+    fn test_id_of_reverse_lookup() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let mut assets = Assets::<MockAsset>::default();
+        let mut library: AssetFolder<&'static str, MockAsset> = AssetFolder::new();
+        let sword = assets.add(MockAsset);
+        let shield = assets.add(MockAsset);
+        let bow = assets.add(MockAsset);
+        library.insert("sword", sword.clone());
+        library.insert("shield", shield.clone());
+        library.insert("bow", bow.clone());
+        library.set_disabled("bow", true);
+
+        assert_eq!(library.id_of(&sword), Some("sword"));
+        assert_eq!(library.id_of(&bow), None);
+        assert_eq!(library.id_of(&assets.add(MockAsset)), None);
+
+        // Replacing and removing keep the reverse map up to date
+        let axe = assets.add(MockAsset);
+        library.insert("sword", axe.clone());
+        assert_eq!(library.id_of(&sword), None);
+        assert_eq!(library.id_of(&axe), Some("sword"));
+        library.remove("shield");
+        assert_eq!(library.id_of(&shield), None);
+
+        // Direct map access bypasses the reverse map, found by scanning
+        library.assets_mut().insert("shield", shield.clone());
+        assert_eq!(library.id_of(&shield), Some("shield"));
+
+        // Handles swapped through get_mut are found by scanning as well
+        let spear = assets.add(MockAsset);
+        *library.get_mut("shield").unwrap() = spear.clone();
+        assert_eq!(library.id_of(&shield), None);
+        assert_eq!(library.id_of(&spear), Some("shield"));
+    }
+
+    #[test]
+    fn test_contains_all_and_missing() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let mut assets = Assets::<MockAsset>::default();
+        let mut library: AssetFolder<&'static str, MockAsset> = AssetFolder::new();
+        library.insert("sword", assets.add(MockAsset));
+        library.insert("shield", assets.add(MockAsset));
+        library.insert("bow", assets.add(MockAsset));
+        library.set_disabled("bow", true);
+
+        assert!(library.contains_all(["sword", "shield"]));
+        assert!(library.contains_all([]));
+        assert!(!library.contains_all(["sword", "bow"]));
+        assert_eq!(
+            library.missing(["axe", "sword", "bow", "shield"]),
+            vec!["axe", "bow"]
+        );
+    }
+
+    #[test]
+    fn test_asset_folder_pending_ids() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let mut assets = Assets::<MockAsset>::default();
+        let mut library: AssetFolder<MockId, MockAsset> = AssetFolder::new();
+        library.insert(MockId(1), assets.add(MockAsset));
+        library.insert(MockId(2), Handle::default());
+        library.insert(MockId(3), assets.reserve_handle());
+
+        let mut pending = library.pending_ids(&assets);
+        pending.sort_by_key(|id| id.0);
+        assert_eq!(pending, vec![MockId(2), MockId(3)]);
+    }
+
+    #[test]
+    fn test_asset_folder_locale_variants() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let mut assets = Assets::<MockAsset>::default();
+        let english = assets.add(MockAsset);
+        let french = assets.add(MockAsset);
+
+        let mut library: AssetFolder<MockId, MockAsset> = AssetFolder::new();
+        library.set_fallback_locale("en");
+        library.set_locale("fr");
+        library.insert_variant(MockId(1), "en", english.clone());
+        assert_eq!(library.get(MockId(1)), Some(&english));
+
+        library.insert_variant(MockId(1), "fr", french.clone());
+        assert_eq!(library.get(MockId(1)), Some(&french));
+
+        library.set_locale("en");
+        assert_eq!(library.get(MockId(1)), Some(&english));
+        assert_eq!(library.variant(MockId(1), "fr"), Some(&french));
+    }
+
+    #[test]
+    fn test_locale_variants_keep_ids_without_matching_locale() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let mut assets = Assets::<MockAsset>::default();
+        let german = assets.add(MockAsset);
+        let spanish = assets.add(MockAsset);
+
+        let mut library: AssetFolder<MockId, MockAsset> = AssetFolder::new();
+        library.set_fallback_locale("en");
+        library.set_locale("fr");
+        library.insert_variant(MockId(1), "es", spanish.clone());
+        library.insert_variant(MockId(1), "de", german.clone());
+
+        assert_eq!(library.get(MockId(1)), Some(&german));
+        library.set_locale("es");
+        assert_eq!(library.get(MockId(1)), Some(&spanish));
+    }
+
+    #[test]
+    fn test_asset_folder_find_matching() {
+        #[derive(Asset, Clone, Reflect, Default)]
+        struct MockAsset;
+
+        let mut library: AssetFolder<&'static str, MockAsset> = AssetFolder::new();
+        for id in [
+            "fireball",
+            "fire_wall",
+            "ice_bolt",
+            "lightning_bolt",
+            "spice",
+        ] {
+            library.insert(id, Handle::default());
+        }
+
+        let sorted = |mut ids: Vec<&'static str>| {
+            ids.sort_unstable();
+            ids
+        };
+
+        // Prefix
+        assert_eq!(
+            sorted(library.find_matching("fire*")),
+            vec!["fire_wall", "fireball"]
+        );
+        // Suffix
+        assert_eq!(
+            sorted(library.find_matching("*_bolt")),
+            vec!["ice_bolt", "lightning_bolt"]
+        );
+        // Contains
+        assert_eq!(
+            sorted(library.find_matching("*ice*")),
+            vec!["ice_bolt", "spice"]
+        );
+        // Exact and no match
+        assert_eq!(library.find_matching("spice"), vec!["spice"]);
+        assert!(library.find_matching("frost*").is_empty());
+        assert_eq!(library.find_matching("*").len(), 5);
+    }
+
+    // ==========================================================================
+    // App-based loading tests
+    // ==========================================================================
+
+    use bevy_common_assets::ron::RonAssetPlugin;
+    use serde::Deserialize;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    #[derive(Asset, Clone, Reflect, Deserialize, Debug)]
+    struct TestSpell {
+        name: String,
+        #[serde(default)]
+        damage: f32,
+    }
+
+    #[derive(Asset, Clone, Reflect, Deserialize, Debug)]
+    struct TestItem {
+        name: String,
+    }
+
+    /// String-backed ID for app-based tests, mirroring the example's ID type.
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+    struct TestId(&'static str);
+
+    impl From<String> for TestId {
+        fn from(s: String) -> Self {
+            TestId(Box::leak(s.into_boxed_str()))
+        }
+    }
+
+    /// A temporary asset root, removed again when dropped.
+    struct TempAssets(PathBuf);
+
+    impl std::ops::Deref for TempAssets {
+        type Target = Path;
+
+        fn deref(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TempAssets {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// Writes `files` into a fresh asset root, removed once the returned
+    /// guard is dropped.
+    fn temp_assets(files: &[(&str, &str)]) -> TempAssets {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let root = std::env::temp_dir().join(format!(
+            "msg_load_folder_test_{}_{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let _ = std::fs::remove_dir_all(&root);
+        for (path, contents) in files {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, contents).unwrap();
+        }
+        TempAssets(root)
+    }
+
+    /// Creates a headless app loading assets from `root`.
+    fn test_app(root: &Path) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(AssetPlugin {
+                file_path: root.to_string_lossy().into_owned(),
+                ..default()
+            })
+            .add_plugins(RonAssetPlugin::<TestSpell>::new(&["spell.ron"]))
+            .add_plugins(RonAssetPlugin::<TestItem>::new(&["item.ron"]));
+        app
+    }
+
+    /// Updates `app` until `done` returns true, panicking after a timeout.
+    fn update_until(app: &mut App, mut done: impl FnMut(&mut App) -> bool) {
+        for _ in 0..2000 {
+            app.update();
+            if done(app) {
+                return;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        panic!("timed out waiting for folder loading");
+    }
+
+    fn is_loaded<A: Asset>(app: &App) -> bool {
+        app.world().resource::<AssetFolderHandle<A>>().is_loaded()
+    }
+
+    #[test]
+    fn test_pending_bytes_from_remote_source() {
+        use bevy::asset::io::memory::{Dir, MemoryAssetReader};
+        use bevy::asset::io::{AssetReader, AssetSourceBuilder, PathStream, Reader};
+
+        /// A mocked remote source counting each file as downloaded once read.
+        struct RemoteReader {
+            inner: MemoryAssetReader,
+            pending: PendingBytes,
+        }
+
+        impl AssetReader for RemoteReader {
+            async fn read<'a>(
+                &'a self,
+                path: &'a Path,
+            ) -> Result<impl Reader + 'a, AssetReaderError> {
+                let reader = AssetReader::read(&self.inner, path).await?;
+                if let Some(data) = self.inner.root.get_asset(path) {
+                    self.pending.received(data.value().len() as u64);
+                }
+                Ok(reader)
+            }
+
+            async fn read_meta<'a>(
+                &'a self,
+                path: &'a Path,
+            ) -> Result<impl Reader + 'a, AssetReaderError> {
+                AssetReader::read_meta(&self.inner, path).await
+            }
+
+            async fn read_directory<'a>(
+                &'a self,
+                path: &'a Path,
+            ) -> Result<Box<PathStream>, AssetReaderError> {
+                AssetReader::read_directory(&self.inner, path).await
+            }
+
+            async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+                AssetReader::is_directory(&self.inner, path).await
+            }
+        }
+
+        let files = [
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/heal.spell.ron", "(name: \"Heal\")"),
+        ];
+        let dir = Dir::default();
+        for (path, content) in files {
+            dir.insert_asset_text(Path::new(path), content);
+        }
+        // The source knows the content lengths up front, e.g. from an index
+        let pending = PendingBytes::default();
+        let total: u64 = files.iter().map(|(_, content)| content.len() as u64).sum();
+        pending.add(total);
+
+        let mut app = App::new();
+        let source_pending = pending.clone();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSourceBuilder::new(move || {
+                Box::new(RemoteReader {
+                    inner: MemoryAssetReader { root: dir.clone() },
+                    pending: source_pending.clone(),
+                })
+            }),
+        )
+        .add_plugins(MinimalPlugins)
+        .add_plugins(AssetPlugin::default())
+        .add_plugins(RonAssetPlugin::<TestSpell>::new(&["spell.ron"]))
+        .add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                .with_pending_bytes(pending.clone()),
+        );
+
+        app.update();
+        let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+        assert_eq!(folder_handle.pending_bytes(), Some(total));
+
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+        assert_eq!(folder_handle.pending_bytes(), None);
+        assert_eq!(pending.get(), 0);
+    }
+
+    #[test]
+    fn test_local_source_has_no_pending_bytes() {
+        let root = temp_assets(&[("spells/fireball.spell.ron", "(name: \"Fireball\")")]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new(
+            "spells",
+            ".spell.ron",
+        ));
+        app.update();
+        let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+        assert_eq!(folder_handle.pending_bytes(), None);
+    }
+
+    #[test]
+    fn test_low_priority_waits_for_high_priority() {
+        let root = temp_assets(&[
+            (
+                "spells/fireball.spell.ron",
+                "(name: \"Fireball\", damage: 50.0)",
+            ),
+            ("items/potion.item.ron", "(name: \"Potion\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<MockId, TestItem>::new(
+            "items",
+            ".item.ron",
+        ))
+        .add_plugins(
+            FolderLoaderPlugin::<MockId, TestSpell>::new("spells", ".spell.ron").with_priority(10),
+        );
+
+        update_until(&mut app, |app| {
+            let low = app.world().resource::<AssetFolderHandle<TestItem>>();
+            if !is_loaded::<TestSpell>(app) {
+                assert!(low.handle.is_none(), "low priority folder started early");
+            }
+            is_loaded::<TestSpell>(app)
+        });
+        update_until(&mut app, |app| is_loaded::<TestItem>(app));
+
+        assert_eq!(
+            app.world()
+                .resource::<AssetFolder<MockId, TestItem>>()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_on_progress_reports_count_changes() {
+        static PROGRESS: std::sync::Mutex<Vec<(usize, usize)>> = std::sync::Mutex::new(Vec::new());
+
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/ice_shard.spell.ron", "(name: \"Ice Shard\")"),
+            ("spells/heal.spell.ron", "(name: \"Heal\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<MockId, TestSpell>::new("spells", ".spell.ron")
+                .on_progress(|loaded, total| PROGRESS.lock().unwrap().push((loaded, total))),
+        );
+
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        for _ in 0..5 {
+            app.update();
+        }
+
+        let progress = PROGRESS.lock().unwrap();
+        assert_eq!(progress.last(), Some(&(3, 3)));
+        assert!(progress.windows(2).all(|w| w[0] != w[1]));
+        assert!(progress.windows(2).all(|w| w[0].0 <= w[1].0));
+    }
+
+    #[test]
+    fn test_on_progress_reports_each_file_with_two_pass() {
+        static PROGRESS: std::sync::Mutex<Vec<(usize, usize)>> = std::sync::Mutex::new(Vec::new());
+
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/ice_shard.spell.ron", "(name: \"Ice Shard\")"),
+            ("spells/heal.spell.ron", "(name: \"Heal\")"),
+        ]);
+        let mut app = test_app(&root);
+        // One file at a time, so every file finishes on its own frame
+        app.add_plugins(
+            FolderLoaderPlugin::<MockId, TestSpell>::new("spells", ".spell.ron")
+                .two_pass(true)
+                .concurrency_hint(1)
+                .on_progress(|loaded, total| PROGRESS.lock().unwrap().push((loaded, total))),
+        );
+
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        assert_eq!(*PROGRESS.lock().unwrap(), vec![(1, 3), (2, 3), (3, 3)]);
+    }
+
+    #[test]
+    fn test_limit_registers_first_files() {
+        let root = temp_assets(&[
+            ("spells/a.spell.ron", "(name: \"A\")"),
+            ("spells/bb.spell.ron", "(name: \"B\")"),
+            ("spells/ccc.spell.ron", "(name: \"C\")"),
+            ("spells/dddd.spell.ron", "(name: \"D\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<MockId, TestSpell>::new("spells", ".spell.ron").limit(2),
+        );
+
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let library = app.world().resource::<AssetFolder<MockId, TestSpell>>();
+        assert_eq!(library.len(), 2);
+        assert!(library.contains(MockId(1)));
+        assert!(library.contains(MockId(2)));
+    }
+
+    #[test]
+    fn test_limit_with_two_pass_skips_loading_the_rest() {
+        let root = temp_assets(&[
+            ("spells/a.spell.ron", "(name: \"A\")"),
+            ("spells/bb.spell.ron", "(name: \"B\")"),
+            ("spells/ccc.spell.ron", "(name: \"C\")"),
+            ("spells/dddd.spell.ron", "(name: \"D\")"),
+        ]);
+        #[derive(Resource, Default)]
+        struct Added(usize);
+
+        let mut app = test_app(&root);
+        app.init_resource::<Added>()
+            .add_systems(
+                Update,
+                |mut events: MessageReader<AssetEvent<TestSpell>>, mut added: ResMut<Added>| {
+                    added.0 += events
+                        .read()
+                        .filter(|event| matches!(event, AssetEvent::Added { .. }))
+                        .count();
+                },
+            )
+            .add_plugins(
+                FolderLoaderPlugin::<MockId, TestSpell>::new("spells", ".spell.ron")
+                    .two_pass(true)
+                    .limit(2),
+            );
+
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        for _ in 0..5 {
+            app.update();
+        }
+
+        let world = app.world();
+        assert_eq!(world.resource::<AssetFolder<MockId, TestSpell>>().len(), 2);
+        assert_eq!(world.resource::<Added>().0, 2);
+        let folder_handle = world.resource::<AssetFolderHandle<TestSpell>>();
+        assert_eq!(
+            folder_handle.skip_reason("spells/dddd.spell.ron"),
+            Some(SkipReason::OverLimit)
+        );
+        assert_eq!(folder_handle.skipped().len(), 2);
+    }
+
+    #[test]
+    fn test_report_after_mixed_load() {
+        // Matching on `.ron` picks up the item file, which has the wrong asset type
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/_disabled.spell.ron", "(name: \"Disabled\")"),
+            ("spells/nested/heal.spell.ron", "(name: \"Heal\")"),
+            ("spells/other/heal.spell.ron", "(name: \"Heal Again\")"),
+            ("spells/potion.item.ron", "(name: \"Potion\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new(
+            "spells", ".ron",
+        ));
+
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let report = app
+            .world()
+            .resource::<AssetFolderHandle<TestSpell>>()
+            .report();
+        let mut loaded = report.loaded.clone();
+        loaded.sort();
+        assert_eq!(
+            loaded,
+            vec!["TestId(\"fireball.spell\")", "TestId(\"heal.spell\")"]
+        );
+        assert_eq!(report.skipped, vec!["spells/_disabled.spell.ron"]);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].0, "spells/potion.item.ron");
+        assert_eq!(report.duplicates.len(), 1);
+        assert_eq!(report.duplicates[0].0, "TestId(\"heal.spell\")");
+
+        let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+        let loaded_folders = app.world().resource::<Assets<LoadedFolder>>();
+        assert_eq!(folder_handle.raw_handle_count(loaded_folders), Some(5));
+
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        let entries = folder_handle.all_entries(library);
+        assert_eq!(entries.len(), 4);
+        assert!(entries.contains(&EntryStatus::Loaded(TestId("fireball.spell"))));
+        assert!(entries.contains(&EntryStatus::Loaded(TestId("heal.spell"))));
+        assert!(entries.iter().any(|entry| matches!(
+            entry,
+            EntryStatus::Failed(path, _) if path == "spells/potion.item.ron"
+        )));
+        assert!(entries.contains(&EntryStatus::Skipped(
+            "spells/_disabled.spell.ron".to_string(),
+            SkipReason::Disabled
+        )));
+
+        #[cfg(feature = "serde")]
+        {
+            let serialized = ron::to_string(&report).unwrap();
+            assert!(serialized.contains("spells/_disabled.spell.ron"));
+            assert!(serialized.contains("spells/potion.item.ron"));
+        }
+    }
+
+    #[test]
+    fn test_added_file_registers_without_rescan() {
+        let root = temp_assets(&[("spells/fireball.spell.ron", "(name: \"Fireball\")")]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new(
+            "spells",
+            ".spell.ron",
+        ));
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        let folder = app
+            .world()
+            .resource::<AssetFolderHandle<TestSpell>>()
+            .handle
+            .clone();
+
+        std::fs::write(root.join("spells/frost.spell.ron"), "(name: \"Frost\")").unwrap();
+        let _added: Handle<TestSpell> = app
+            .world()
+            .resource::<AssetServer>()
+            .load("spells/frost.spell.ron");
+
+        update_until(&mut app, |app| {
+            app.world()
+                .resource::<AssetFolder<TestId, TestSpell>>()
+                .contains(TestId("frost"))
+        });
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        assert_eq!(library.len(), 2);
+        assert!(library.contains(TestId("fireball")));
+        // The folder itself was not reloaded
+        assert_eq!(
+            app.world()
+                .resource::<AssetFolderHandle<TestSpell>>()
+                .handle,
+            folder
+        );
+    }
+
+    #[test]
+    fn test_path_of_added_file() {
+        let root = temp_assets(&[("spells/fireball.spell.ron", "(name: \"Fireball\")")]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new(
+            "spells",
+            ".spell.ron",
+        ));
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        std::fs::write(root.join("spells/frost.spell.ron"), "(name: \"Frost\")").unwrap();
+        let _added: Handle<TestSpell> = app
+            .world()
+            .resource::<AssetServer>()
+            .load("spells/frost.spell.ron");
+        update_until(&mut app, |app| {
+            app.world()
+                .resource::<AssetFolder<TestId, TestSpell>>()
+                .contains(TestId("frost"))
+        });
+
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        assert_eq!(
+            library.path_of(TestId("frost")),
+            Some(Path::new("spells/frost.spell.ron"))
+        );
+    }
+
+    #[test]
+    fn test_load_with_non_copy_id() {
+        use std::sync::Arc;
+
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/heal.spell.ron", "(name: \"Heal\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<Arc<str>, TestSpell>::new(
+            "spells",
+            ".spell.ron",
+        ));
+
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let library = app.world().resource::<AssetFolder<Arc<str>, TestSpell>>();
+        let assets = app.world().resource::<Assets<TestSpell>>();
+        let fireball = library.get("fireball".into()).unwrap();
+        assert_eq!(assets.get(fireball).unwrap().name, "Fireball");
+        assert!(library.contains("heal".into()));
+    }
+
+    #[test]
+    fn test_locale_variants_select_and_fall_back() {
+        let root = temp_assets(&[
+            ("text/greeting.en.spell.ron", "(name: \"Hello\")"),
+            ("text/greeting.fr.spell.ron", "(name: \"Bonjour\")"),
+            ("text/farewell.en.spell.ron", "(name: \"Goodbye\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("text", ".spell.ron")
+                .with_locale_variants("fr", "en"),
+        );
+
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let name = |app: &App, id: &'static str| {
+            let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+            let assets = app.world().resource::<Assets<TestSpell>>();
+            assets
+                .get(library.get(TestId(id))?)
+                .map(|spell| spell.name.clone())
+        };
+        assert_eq!(name(&app, "greeting").as_deref(), Some("Bonjour"));
+        assert_eq!(name(&app, "farewell").as_deref(), Some("Goodbye"));
+        assert_eq!(
+            app.world()
+                .resource::<AssetFolder<TestId, TestSpell>>()
+                .len(),
+            2
+        );
+
+        app.world_mut()
+            .resource_mut::<AssetFolder<TestId, TestSpell>>()
+            .set_locale("en");
+        assert_eq!(name(&app, "greeting").as_deref(), Some("Hello"));
+    }
+
+    #[test]
+    fn test_on_failure_registers_substitute() {
+        // The item file matches `.ron` but has the wrong asset type, so it fails
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/potion.item.ron", "(name: \"Potion\")"),
+            ("placeholder.spell.ron", "(name: \"Placeholder\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".ron")
+                .on_failure(|_, asset_server| Some(asset_server.load("placeholder.spell.ron"))),
+        );
+
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+        assert!(folder_handle.report().failed.is_empty());
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        let substitute = library.get(TestId("potion.item")).unwrap();
+        assert_eq!(
+            substitute.path().unwrap().path(),
+            Path::new("placeholder.spell.ron")
+        );
+    }
+
+    #[test]
+    fn test_reload_only_if_modified_skips_unchanged_folder() {
+        let root = temp_assets(&[("spells/fireball.spell.ron", "(name: \"Fireball\")")]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                .reload_only_if_modified(true),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        let first = app
+            .world()
+            .resource::<AssetFolderHandle<TestSpell>>()
+            .handle
+            .as_ref()
+            .unwrap()
+            .id();
+
+        // Unchanged folder: the request is dropped without re-registration
+        app.world_mut()
+            .resource_mut::<AssetFolderHandle<TestSpell>>()
+            .request_reload();
+        app.update();
+        let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+        assert!(!folder_handle.is_reload_requested());
+        assert!(folder_handle.is_loaded());
+        assert_eq!(folder_handle.handle.as_ref().map(Handle::id), Some(first));
+        assert!(
+            app.world()
+                .resource::<Assets<LoadedFolder>>()
+                .get(first)
+                .is_some()
+        );
+        assert_eq!(folder_handle.report().loaded.len(), 1);
+
+        // Touching a file triggers a full rescan
+        let file = std::fs::File::options()
+            .write(true)
+            .open(root.join("spells/fireball.spell.ron"))
+            .unwrap();
+        file.set_modified(SystemTime::now() + std::time::Duration::from_secs(60))
+            .unwrap();
+        app.world_mut()
+            .resource_mut::<AssetFolderHandle<TestSpell>>()
+            .request_reload();
+        app.update();
+        assert!(!is_loaded::<TestSpell>(&app));
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        assert_eq!(
+            app.world()
+                .resource::<AssetFolder<TestId, TestSpell>>()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_dependency_fn_loads_referenced_assets() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("items/fireball.item.ron", "(name: \"Scroll\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                .with_dependency_fn(|spell| {
+                    vec![format!("items/{}.item.ron", spell.name.to_lowercase())]
+                })
+                .wait_for_dependencies(true),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let items = app.world().resource::<Assets<TestItem>>();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items.iter().next().unwrap().1.name, "Scroll");
+        assert!(
+            app.world()
+                .resource::<AssetFolderHandle<TestSpell>>()
+                .report()
+                .failed
+                .is_empty()
+        );
+    }
+
+    /// Collects the messages of all log events.
+    struct CaptureLayer(std::sync::Arc<std::sync::Mutex<Vec<String>>>);
+
+    struct MessageVisitor(String);
+
+    impl bevy::log::tracing::field::Visit for MessageVisitor {
+        fn record_debug(
+            &mut self,
+            field: &bevy::log::tracing::field::Field,
+            value: &dyn std::fmt::Debug,
+        ) {
+            if field.name() == "message" {
+                self.0 = format!("{value:?}");
+            }
+        }
+    }
+
+    impl<S: bevy::log::tracing::Subscriber> bevy::log::tracing_subscriber::Layer<S> for CaptureLayer {
+        fn on_event(
+            &self,
+            event: &bevy::log::tracing::Event<'_>,
+            _ctx: bevy::log::tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let mut visitor = MessageVisitor(String::new());
+            event.record(&mut visitor);
+            self.0.lock().unwrap().push(visitor.0);
+        }
+    }
+
+    #[test]
+    fn test_logs_include_asset_type_name() {
+        use bevy::ecs::schedule::ExecutorKind;
+        use bevy::log::tracing_subscriber::layer::SubscriberExt;
+
+        let root = temp_assets(&[("spells/fireball.spell.ron", "(name: \"Fireball\")")]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new(
+            "spells",
+            ".spell.ron",
+        ));
+        // Run systems on this thread so they see the scoped subscriber
+        app.edit_schedule(Update, |schedule| {
+            schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        });
+
+        let messages = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let subscriber =
+            bevy::log::tracing_subscriber::registry().with(CaptureLayer(messages.clone()));
+        bevy::log::tracing::subscriber::with_default(subscriber, || {
+            update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        });
+
+        let messages = messages.lock().unwrap();
+        assert!(messages.iter().any(|message| {
+            message.starts_with(&format!("[{}] Processed", type_name::<TestSpell>()))
+        }));
+    }
+
+    #[test]
+    fn test_duplicate_asset_type_warns() {
+        use bevy::log::tracing_subscriber::layer::SubscriberExt;
+
+        let root = temp_assets(&[("spells/fireball.spell.ron", "(name: \"Fireball\")")]);
+        let mut app = test_app(&root);
+        let messages = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let subscriber =
+            bevy::log::tracing_subscriber::registry().with(CaptureLayer(messages.clone()));
+        bevy::log::tracing::subscriber::with_default(subscriber, || {
+            app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new(
+                "spells",
+                ".spell.ron",
+            ));
+            assert!(messages.lock().unwrap().is_empty());
+            app.add_plugins(FolderLoaderPlugin::<String, TestSpell>::new(
+                "more_spells",
+                ".spell.ron",
+            ));
+        });
+
+        let messages = messages.lock().unwrap();
+        assert!(messages.iter().any(|message| {
+            message.starts_with(&format!(
+                "[{}] Another FolderLoaderPlugin",
+                type_name::<TestSpell>()
+            )) && message.contains("'more_spells'")
+        }));
+    }
+
+    #[test]
+    fn test_duplicate_folder_is_skipped() {
+        use bevy::log::tracing_subscriber::layer::SubscriberExt;
+
+        let root = temp_assets(&[("spells/fireball.spell.ron", "(name: \"Fireball\")")]);
+        let mut app = test_app(&root);
+        let messages = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let subscriber =
+            bevy::log::tracing_subscriber::registry().with(CaptureLayer(messages.clone()));
+        bevy::log::tracing::subscriber::with_default(subscriber, || {
+            app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new(
+                "spells",
+                ".spell.ron",
+            ));
+            app.add_plugins(FolderLoaderPlugin::<String, TestSpell>::new(
+                "spells",
+                ".spell.ron",
+            ));
+        });
+
+        let messages = messages.lock().unwrap();
+        assert!(messages.iter().any(|message| {
+            message.contains("Folder 'spells' is already loaded for this asset type")
+        }));
+        assert!(
+            !app.world()
+                .contains_resource::<AssetFolder<String, TestSpell>>()
+        );
+        drop(messages);
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        assert!(library.contains(TestId("fireball")));
+    }
+
+    #[test]
+    fn test_duplicate_folder_ignores_trailing_slash() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("more_spells/heal.spell.ron", "(name: \"Heal\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new(
+            "spells",
+            ".spell.ron",
+        ));
+        // Overlapping in one folder skips the whole plugin
+        app.add_plugins(FolderLoaderPlugin::<String, TestSpell>::with_folders(
+            &["more_spells", "spells/"],
+            ".spell.ron",
+        ));
+        assert!(
+            !app.world()
+                .contains_resource::<AssetFolder<String, TestSpell>>()
+        );
+
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        assert_eq!(library.len(), 1);
+        assert!(library.contains(TestId("fireball")));
+    }
+
+    #[test]
+    fn test_staged_reload_keeps_live_library_until_complete() {
+        let root = temp_assets(&[("spells/fireball.spell.ron", "(name: \"Fireball\")")]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                .staged_reloads(true),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        std::fs::write(root.join("spells/heal.spell.ron"), "(name: \"Heal\")").unwrap();
+        app.world_mut()
+            .resource_mut::<AssetFolderHandle<TestSpell>>()
+            .request_reload();
+        app.update();
+
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        assert!(library.is_staging());
+        assert_eq!(library.len(), 1);
+        assert!(library.contains(TestId("fireball")));
+
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        assert!(!library.is_staging());
+        assert_eq!(library.len(), 2);
+        assert!(library.contains(TestId("heal")));
+    }
+
+    #[test]
+    fn test_staged_reload_carries_over_settings() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/heal.spell.ron", "(name: \"Heal\")"),
+            ("placeholder.spell.ron", "(name: \"Placeholder\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                .staged_reloads(true),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        let placeholder: Handle<TestSpell> = app
+            .world()
+            .resource::<AssetServer>()
+            .load("placeholder.spell.ron");
+        let mut library = app
+            .world_mut()
+            .resource_mut::<AssetFolder<TestId, TestSpell>>();
+        library.set_disabled(TestId("heal"), true);
+        library.set_fallback(placeholder.clone());
+        library.freeze();
+
+        app.world_mut()
+            .resource_mut::<AssetFolderHandle<TestSpell>>()
+            .request_reload();
+        app.update();
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        let staging = library.staging.as_deref().unwrap();
+        assert!(staging.is_disabled(TestId("heal")));
+        assert!(staging.is_frozen());
+        assert_eq!(staging.fallback(), &placeholder);
+
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        assert!(!library.is_staging());
+        assert!(library.is_disabled(TestId("heal")));
+        assert!(!library.contains(TestId("heal")));
+        assert!(library.is_frozen());
+        assert_eq!(library.get_or_default(TestId("heal")), &placeholder);
+        assert!(library.contains(TestId("fireball")));
+    }
+
+    #[test]
+    fn test_plugin_group_registers_all_folders() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("items/potion.item.ron", "(name: \"Potion\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPluginGroup::new()
+                .add_folder::<TestId, TestSpell>("spells", ".spell.ron")
+                .with(FolderLoaderPlugin::<TestId, TestItem>::new(
+                    "items",
+                    ".item.ron",
+                )),
+        );
+        update_until(&mut app, |app| {
+            is_loaded::<TestSpell>(app) && is_loaded::<TestItem>(app)
+        });
+
+        let world = app.world();
+        assert!(
+            world
+                .resource::<AssetFolder<TestId, TestSpell>>()
+                .contains(TestId("fireball"))
+        );
+        assert!(
+            world
+                .resource::<AssetFolder<TestId, TestItem>>()
+                .contains(TestId("potion"))
+        );
+    }
+
+    #[test]
+    fn test_allow_empty_id_registers_default_entry() {
+        let root = temp_assets(&[
+            ("spells/.spell.ron", "(name: \"Default\")"),
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                .allow_empty_id(true),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        // Dot-files aren't listed by the file source, so load it explicitly
+        let _default: Handle<TestSpell> = app
+            .world()
+            .resource::<AssetServer>()
+            .load("spells/.spell.ron");
+        update_until(&mut app, |app| {
+            app.world()
+                .resource::<AssetFolder<TestId, TestSpell>>()
+                .contains(TestId::default())
+        });
+
+        let world = app.world();
+        let library = world.resource::<AssetFolder<TestId, TestSpell>>();
+        assert_eq!(library.len(), 2);
+        let handle = library.get(TestId::default()).unwrap();
+        assert_eq!(
+            world
+                .resource::<Assets<TestSpell>>()
+                .get(handle)
+                .unwrap()
+                .name,
+            "Default"
+        );
+    }
+
+    #[test]
+    fn test_allow_empty_id_finds_dot_file_with_two_pass() {
+        let root = temp_assets(&[
+            ("spells/.spell.ron", "(name: \"Default\")"),
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                .two_pass(true)
+                .allow_empty_id(true),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let world = app.world();
+        let library = world.resource::<AssetFolder<TestId, TestSpell>>();
+        assert_eq!(library.len(), 2);
+        let handle = library.get(TestId::default()).unwrap();
+        assert_eq!(
+            world
+                .resource::<Assets<TestSpell>>()
+                .get(handle)
+                .unwrap()
+                .name,
+            "Default"
+        );
+        assert_eq!(world.resource::<AssetFolderHandle<TestSpell>>().total(), 2);
+    }
+
+    #[cfg(feature = "manifest")]
+    #[test]
+    fn test_manifest_assigns_listed_ids() {
+        let root = temp_assets(&[
+            (
+                "spells/index.ron",
+                r#"[
+                    (id: "fireball", file: "fb.spell.ron"),
+                    (id: "heal", file: "restore.spell.ron"),
+                    (id: "gone", file: "gone.spell.ron"),
+                ]"#,
+            ),
+            ("spells/fb.spell.ron", "(name: \"Fireball\")"),
+            ("spells/restore.spell.ron", "(name: \"Heal\")"),
+            ("spells/unlisted.spell.ron", "(name: \"Unlisted\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<String, TestSpell>::new("spells", ".spell.ron")
+                .with_manifest("spells/index.ron"),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let library = app.world().resource::<AssetFolder<String, TestSpell>>();
+        let mut ids: Vec<_> = library.keys().collect();
+        ids.sort();
+        assert_eq!(ids, vec!["fireball", "heal"]);
+        let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+        assert!(matches!(
+            folder_handle.errors().as_slice(),
+            [FolderLoadError::ManifestFileMissing { id, path }]
+                if id == "gone" && path == "spells/gone.spell.ron"
+        ));
+    }
+
+    #[cfg(feature = "manifest")]
+    #[test]
+    fn test_invalid_manifest_fails_load() {
+        let root = temp_assets(&[
+            ("spells/index.ron", "[(id: \"fireball\")]"),
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<String, TestSpell>::new("spells", ".spell.ron")
+                .with_manifest("spells/index.ron"),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        assert!(
+            app.world()
+                .resource::<AssetFolder<String, TestSpell>>()
+                .is_empty()
+        );
+        let mut folder_handle = app
+            .world_mut()
+            .resource_mut::<AssetFolderHandle<TestSpell>>();
+        assert!(matches!(
+            folder_handle.errors().as_slice(),
+            [FolderLoadError::InvalidManifest { path, .. }] if path == "spells/index.ron"
+        ));
+        let (failed, _) = folder_handle.take_problems();
+        assert_eq!(failed, vec!["spells/index.ron"]);
+    }
+
+    #[cfg(feature = "manifest")]
+    #[test]
+    fn test_manifest_warns_about_hot_reload() {
+        use bevy::log::tracing_subscriber::layer::SubscriberExt;
+
+        let root = temp_assets(&[("spells/index.ron", "[]")]);
+        let mut app = test_app(&root);
+        let messages = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let subscriber =
+            bevy::log::tracing_subscriber::registry().with(CaptureLayer(messages.clone()));
+        bevy::log::tracing::subscriber::with_default(subscriber, || {
+            app.add_plugins(
+                FolderLoaderPlugin::<String, TestSpell>::new("spells", ".spell.ron")
+                    .with_manifest("spells/index.ron")
+                    .hot_reload(true),
+            );
+        });
+
+        let messages = messages.lock().unwrap();
+        assert!(
+            messages
+                .iter()
+                .any(|message| message.contains("Hot reload has no effect")),
+            "{messages:?}"
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_registers_loader() {
+        let root = temp_assets(&[
+            (
+                "spells/fireball.spell.json",
+                r#"{"name": "Fireball", "damage": 50.0}"#,
+            ),
+            ("spells/heal.spell.json", r#"{"name": "Heal"}"#),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::json(
+            "spells",
+            ".spell.json",
+        ));
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        let handle = library.get(TestId("fireball")).unwrap();
+        let spell = app
+            .world()
+            .resource::<Assets<TestSpell>>()
+            .get(handle)
+            .unwrap();
+        assert_eq!(spell.damage, 50.0);
+        assert!(library.contains(TestId("heal")));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_warns_about_unregistered_extensions() {
+        use bevy::log::tracing_subscriber::layer::SubscriberExt;
+
+        let root = temp_assets(&[]);
+        let mut app = test_app(&root);
+        let messages = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let subscriber =
+            bevy::log::tracing_subscriber::registry().with(CaptureLayer(messages.clone()));
+        bevy::log::tracing::subscriber::with_default(subscriber, || {
+            app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::json(
+                "spells",
+                ".spell.json",
+            ));
+            app.add_plugins(FolderLoaderPlugin::<String, TestSpell>::json(
+                "more_spells",
+                ".spell.json",
+            ));
+        });
+        let is_extension_warning =
+            |message: &String| message.contains("A JsonAssetPlugin is already added");
+        assert!(!messages.lock().unwrap().iter().any(is_extension_warning));
+
+        bevy::log::tracing::subscriber::with_default(
+            bevy::log::tracing_subscriber::registry().with(CaptureLayer(messages.clone())),
+            || {
+                app.add_plugins(FolderLoaderPlugin::<MockId, TestSpell>::json(
+                    "legacy_spells",
+                    ".legacy.json",
+                ));
+            },
+        );
+        let messages = messages.lock().unwrap();
+        assert!(messages.iter().any(|message| {
+            is_extension_warning(message) && message.contains("\"legacy.json\"")
+        }));
+    }
+
+    #[test]
+    fn test_skipped_lists_paths_with_reasons() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/_draft.spell.ron", "(name: \"Draft\")"),
+            ("spells/potion.item.ron", "(name: \"Potion\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new(
+            "spells",
+            ".spell.ron",
+        ));
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+        let mut skipped = folder_handle.skipped().to_vec();
+        skipped.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            skipped,
+            vec![
+                (
+                    PathBuf::from("spells/_draft.spell.ron"),
+                    SkipReason::Disabled
+                ),
+                (
+                    PathBuf::from("spells/potion.item.ron"),
+                    SkipReason::WrongExtension
+                ),
+            ]
+        );
+        assert_eq!(
+            folder_handle.skip_reason("spells/_draft.spell.ron"),
+            Some(SkipReason::Disabled)
+        );
+        assert_eq!(folder_handle.skip_reason("spells/fireball.spell.ron"), None);
+    }
+
+    #[test]
+    fn test_async_id_extraction_registers_and_skips() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/heal.spell.ron", "(name: \"Heal\")"),
+            ("spells/potion.item.ron", "(name: \"Potion\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                .async_id_extraction(true),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        assert_eq!(library.len(), 2);
+        assert!(library.contains(TestId("fireball")));
+        assert!(library.contains(TestId("heal")));
+        let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+        assert_eq!(folder_handle.total(), 2);
+        assert_eq!(
+            folder_handle.report().skipped,
+            vec!["spells/potion.item.ron"]
+        );
+    }
+
+    #[test]
+    fn test_async_id_extraction_defers_discovery() {
+        for async_ids in [false, true] {
+            let root = temp_assets(&[("spells/fireball.spell.ron", "(name: \"Fireball\")")]);
+            let mut app = test_app(&root);
+            app.add_plugins(
+                FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                    .async_id_extraction(async_ids),
+            );
+            // Run up to the frame the loading system first sees the resolved folder
+            update_until(&mut app, |app| {
+                let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+                folder_handle.handle.as_ref().is_some_and(|handle| {
+                    app.world()
+                        .resource::<Assets<LoadedFolder>>()
+                        .contains(handle)
+                })
+            });
+
+            let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+            assert_eq!(library.is_empty(), async_ids);
+            let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+            assert_eq!(folder_handle.phase() == Phase::Done, !async_ids);
+
+            update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+            let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+            assert!(library.contains(TestId("fireball")));
+        }
+    }
+
+    #[test]
+    fn test_two_pass_knows_total_before_loading() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/nested/heal.spell.ron", "(name: \"Heal\")"),
+            ("spells/potion.item.ron", "(name: \"Potion\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron").two_pass(true),
+        );
+        app.update();
+        assert_eq!(
+            app.world()
+                .resource::<AssetFolderHandle<TestSpell>>()
+                .phase(),
+            Phase::Discovering
+        );
+
+        update_until(&mut app, |app| {
+            app.world()
+                .resource::<AssetFolderHandle<TestSpell>>()
+                .phase()
+                != Phase::Discovering
+        });
+        let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+        assert_eq!(folder_handle.phase(), Phase::Loading);
+        assert_eq!(folder_handle.total(), 2);
+        assert!(
+            app.world()
+                .resource::<AssetFolder<TestId, TestSpell>>()
+                .is_empty()
+        );
+
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        assert_eq!(
+            app.world()
+                .resource::<AssetFolder<TestId, TestSpell>>()
+                .len(),
+            2
+        );
+        // Non-matching files are never loaded
+        assert!(app.world().resource::<Assets<TestItem>>().is_empty());
+    }
+
+    #[test]
+    fn test_on_skip_called_per_skipped_file() {
+        static SKIPS: std::sync::Mutex<Vec<(String, SkipReason)>> =
+            std::sync::Mutex::new(Vec::new());
+
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/_disabled.spell.ron", "(name: \"Disabled\")"),
+            ("spells/potion.item.ron", "(name: \"Potion\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron").on_skip(
+                |path, reason| {
+                    let name = path.file_name().unwrap().to_string_lossy().into_owned();
+                    SKIPS.lock().unwrap().push((name, reason));
+                },
+            ),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let report = app
+            .world()
+            .resource::<AssetFolderHandle<TestSpell>>()
+            .report();
+        let mut skips = SKIPS.lock().unwrap().clone();
+        skips.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(skips.len(), report.skipped.len());
+        assert_eq!(
+            skips,
+            vec![
+                ("_disabled.spell.ron".to_string(), SkipReason::Disabled),
+                ("potion.item.ron".to_string(), SkipReason::WrongExtension),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stable_id_hash_is_fixed() {
+        // FNV-1a reference values
+        assert_eq!(stable_id_hash(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(stable_id_hash("a"), 0xaf63_dc4c_8601_ec8c);
+        assert_ne!(stable_id_hash("fireball"), stable_id_hash("heal"));
+    }
+
+    #[test]
+    fn test_numeric_keys_round_trip() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/heal.spell.ron", "(name: \"Heal\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                .with_numeric_keys(true),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        let key = library.numeric_key(TestId("fireball")).unwrap();
+        assert_eq!(key, stable_id_hash("fireball"));
+        assert_eq!(library.id_from_numeric(key), Some(TestId("fireball")));
+        assert_eq!(
+            library.id_from_numeric(stable_id_hash("heal")),
+            Some(TestId("heal"))
+        );
+        assert_eq!(library.id_from_numeric(stable_id_hash("missing")), None);
+    }
+
+    #[test]
+    fn test_folder_defaults_fill_unspecified_fields() {
+        let root = temp_assets(&[
+            ("spells/_defaults.spell.ron", "(name: \"\", damage: 10.0)"),
+            (
+                "spells/fireball.spell.ron",
+                "(name: \"Fireball\", damage: 25.0)",
+            ),
+            ("spells/heal.spell.ron", "(name: \"Heal\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                .with_folder_defaults("_defaults.spell.ron", |spell, defaults| {
+                    if spell.damage == 0.0 {
+                        spell.damage = defaults.damage;
+                    }
+                }),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let world = app.world();
+        let library = world.resource::<AssetFolder<TestId, TestSpell>>();
+        let spells = world.resource::<Assets<TestSpell>>();
+        let damage = |id| spells.get(library.get(id).unwrap()).unwrap().damage;
+        assert_eq!(library.len(), 2);
+        assert_eq!(damage(TestId("fireball")), 25.0);
+        assert_eq!(damage(TestId("heal")), 10.0);
+        assert!(
+            world
+                .resource::<AssetFolderHandle<TestSpell>>()
+                .report()
+                .skipped
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_folder_defaults_are_cloned_once() {
+        static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Asset, Reflect, Deserialize)]
+        struct CountedSpell {
+            #[serde(default)]
+            damage: f32,
+        }
+
+        impl Clone for CountedSpell {
+            fn clone(&self) -> Self {
+                CLONES.fetch_add(1, Ordering::Relaxed);
+                Self {
+                    damage: self.damage,
+                }
+            }
+        }
+
+        let root = temp_assets(&[
+            ("spells/_defaults.counted.ron", "(damage: 10.0)"),
+            ("spells/fireball.counted.ron", "(damage: 25.0)"),
+            ("spells/heal.counted.ron", "()"),
+            ("spells/frost.counted.ron", "()"),
+        ]);
+        let mut app = test_app(&root);
+        // Loading one file at a time spreads registration over several frames
+        app.add_plugins((
+            RonAssetPlugin::<CountedSpell>::new(&["counted.ron"]),
+            FolderLoaderPlugin::<TestId, CountedSpell>::new("spells", ".counted.ron")
+                .two_pass(true)
+                .concurrency_hint(1)
+                .with_folder_defaults("_defaults.counted.ron", |spell, defaults| {
+                    if spell.damage == 0.0 {
+                        spell.damage = defaults.damage;
+                    }
+                }),
+        ));
+        update_until(&mut app, |app| is_loaded::<CountedSpell>(app));
+
+        let world = app.world();
+        let library = world.resource::<AssetFolder<TestId, CountedSpell>>();
+        let spells = world.resource::<Assets<CountedSpell>>();
+        assert_eq!(library.len(), 3);
+        assert_eq!(
+            spells
+                .get(library.get(TestId("heal")).unwrap())
+                .unwrap()
+                .damage,
+            10.0
+        );
+        assert_eq!(CLONES.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_take_problems_retries_only_problem_paths() {
+        static STRICT: AtomicBool = AtomicBool::new(true);
+
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/heal.spell.ron", "(name: \"Draft\")"),
+            ("spells/_disabled.spell.ron", "(name: \"Disabled\")"),
+            ("spells/potion.item.ron", "(name: \"Potion\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".ron").with_validator(
+                Box::new(|spell| {
+                    if STRICT.load(Ordering::Relaxed) && spell.name == "Draft" {
+                        Err("draft spell".to_string())
+                    } else {
+                        Ok(())
+                    }
+                }),
+            ),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        // The item file has the wrong asset type, which a retry can't fix
+        STRICT.store(false, Ordering::Relaxed);
+        let (failed, skipped) = app
+            .world_mut()
+            .resource_mut::<AssetFolderHandle<TestSpell>>()
+            .take_problems();
+        assert_eq!(failed, vec!["spells/heal.spell.ron"]);
+        assert_eq!(skipped, vec!["spells/_disabled.spell.ron"]);
+        assert!(!is_loaded::<TestSpell>(&app));
+
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        let report = app
+            .world()
+            .resource::<AssetFolderHandle<TestSpell>>()
+            .report();
+        // Fireball isn't reloaded and the skipped file isn't re-attempted
+        assert_eq!(
+            report.loaded,
+            vec!["TestId(\"fireball.spell\")", "TestId(\"heal.spell\")"]
+        );
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].0, "spells/potion.item.ron");
+        assert!(report.skipped.is_empty());
+    }
+
+    #[test]
+    fn test_validate_id_charset_rejects_spaces() {
+        static SKIPS: std::sync::Mutex<Vec<SkipReason>> = std::sync::Mutex::new(Vec::new());
+
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/ice bolt.spell.ron", "(name: \"Ice Bolt\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                .validate_id_charset(|id| {
+                    id.chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+                })
+                .on_skip(|_, reason| SKIPS.lock().unwrap().push(reason)),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        assert_eq!(library.len(), 1);
+        assert!(library.contains(TestId("fireball")));
+        assert_eq!(*SKIPS.lock().unwrap(), vec![SkipReason::InvalidIdChars]);
+    }
+
+    #[test]
+    fn test_folder_discovered_fires_once_with_total() {
+        #[derive(Resource, Default)]
+        struct Discovered(Vec<usize>);
+
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/heal.spell.ron", "(name: \"Heal\")"),
+            ("spells/potion.item.ron", "(name: \"Potion\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new(
+            "spells",
+            ".spell.ron",
+        ))
+        .init_resource::<Discovered>()
+        .add_systems(
+            Update,
+            |mut messages: MessageReader<FolderDiscovered<TestSpell>>,
+             mut discovered: ResMut<Discovered>| {
+                discovered
+                    .0
+                    .extend(messages.read().map(|message| message.total_files));
+            },
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        for _ in 0..3 {
+            app.update();
+        }
+
+        assert_eq!(app.world().resource::<Discovered>().0, vec![2]);
+    }
+
+    #[test]
+    fn test_folder_load_completed_fires_once() {
+        #[derive(Resource, Default)]
+        struct Completed(Vec<(&'static str, usize, Vec<String>)>);
+
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/heal.spell.ron", "(name: \"Heal\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new(
+            "spells",
+            ".spell.ron",
+        ))
+        .init_resource::<Completed>()
+        .add_systems(
+            Update,
+            |mut messages: MessageReader<FolderLoadCompleted<TestSpell>>,
+             mut completed: ResMut<Completed>| {
+                completed.0.extend(
+                    messages.read().map(|message| {
+                        (message.folder_path, message.loaded, message.failed.clone())
+                    }),
+                );
+            },
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        for _ in 0..3 {
+            app.update();
+        }
+
+        assert_eq!(
+            app.world().resource::<Completed>().0,
+            vec![("spells", 2, Vec::new())]
+        );
+    }
+
+    #[test]
+    fn test_asset_registered_fires_once_per_asset() {
+        #[derive(Resource, Default)]
+        struct Registered(Vec<TestId>);
+
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/heal.spell.ron", "(name: \"Heal\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new(
+            "spells",
+            ".spell.ron",
+        ))
+        .init_resource::<Registered>()
+        .add_systems(
+            Update,
+            |mut messages: MessageReader<AssetRegistered<TestId, TestSpell>>,
+             library: Res<AssetFolder<TestId, TestSpell>>,
+             mut registered: ResMut<Registered>| {
+                for message in messages.read() {
+                    assert_eq!(library.get(message.id), Some(&message.handle));
+                    registered.0.push(message.id);
+                }
+            },
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        for _ in 0..3 {
+            app.update();
+        }
+
+        let mut registered = app.world().resource::<Registered>().0.clone();
+        registered.sort_by_key(|id| id.0);
+        assert_eq!(registered, vec![TestId("fireball"), TestId("heal")]);
+    }
+
+    #[test]
+    fn test_spawn_from_folder() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/heal.spell.ron", "(name: \"Heal\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new(
+            "spells",
+            ".spell.ron",
+        ));
+        // Reflectable for inspectors when the ID type is
+        app.register_type::<AssetRef<String, TestSpell>>();
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        SpawnFromFolder::<TestId, TestSpell>::new().apply(app.world_mut());
+        let world = app.world_mut();
+        let refs: Vec<_> = world
+            .query::<&AssetRef<TestId, TestSpell>>()
+            .iter(world)
+            .cloned()
+            .collect();
+        assert_eq!(refs.len(), 2);
+        let library = world.resource::<AssetFolder<TestId, TestSpell>>();
+        for asset_ref in refs {
+            assert_eq!(library.get(asset_ref.id), Some(&asset_ref.handle));
+        }
+    }
+
+    #[test]
+    fn test_library_from_world() {
+        let root = temp_assets(&[("spells/fireball.spell.ron", "(name: \"Fireball\")")]);
+        let mut app = test_app(&root);
+        assert!(AssetFolder::<TestId, TestSpell>::from_world(app.world()).is_none());
+
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new(
+            "spells",
+            ".spell.ron",
+        ));
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let library = AssetFolder::<TestId, TestSpell>::from_world(app.world()).unwrap();
+        assert_eq!(library.len(), 1);
+        assert!(library.contains(TestId("fireball")));
+    }
+
+    #[test]
+    fn test_reset_gives_independent_load_cycles() {
+        let root = temp_assets(&[("spells/fireball.spell.ron", "(name: \"Fireball\")")]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new(
+            "spells",
+            ".spell.ron",
+        ));
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        app.world_mut()
+            .resource_mut::<AssetFolder<TestId, TestSpell>>()
+            .set_disabled(TestId("fireball"), true);
+
+        FolderLoaderPlugin::<TestId, TestSpell>::reset(app.world_mut());
+        let world = app.world();
+        assert!(
+            world
+                .resource::<AssetFolder<TestId, TestSpell>>()
+                .is_empty()
+        );
+        assert_eq!(
+            world.resource::<AssetFolderHandle<TestSpell>>().report(),
+            LoadReport::default()
+        );
+
+        std::fs::write(root.join("spells/heal.spell.ron"), "(name: \"Heal\")").unwrap();
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        assert_eq!(library.keys().count(), 2);
+        assert!(library.contains(TestId("fireball")));
+        assert!(library.contains(TestId("heal")));
+    }
+
+    #[test]
+    fn test_previews_load_only_on_request() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/fireball.item.ron", "(name: \"Fireball Preview\")"),
+            ("spells/heal.spell.ron", "(name: \"Heal\")"),
+            ("spells/heal.item.ron", "(name: \"Heal Preview\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                .two_pass(true)
+                .with_preview::<TestItem>(".item.ron"),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(app.world().resource::<Assets<TestItem>>().is_empty());
+        assert!(
+            app.world()
+                .resource::<AssetFolder<TestId, TestItem>>()
+                .is_empty()
+        );
+
+        app.world_mut()
+            .resource_mut::<AssetFolder<TestId, TestSpell>>()
+            .request_preview(TestId("heal"));
+        update_until(&mut app, |app| {
+            let previews = app.world().resource::<AssetFolder<TestId, TestItem>>();
+            previews
+                .get(TestId("heal"))
+                .is_some_and(|handle| app.world().resource::<Assets<TestItem>>().contains(handle))
+        });
+
+        let world = app.world();
+        let previews = world.resource::<AssetFolder<TestId, TestItem>>();
+        let items = world.resource::<Assets<TestItem>>();
+        assert_eq!(previews.len(), 1);
+        assert_eq!(items.len(), 1);
+        assert_eq!(
+            items
+                .get(previews.get(TestId("heal")).unwrap())
+                .unwrap()
+                .name,
+            "Heal Preview"
+        );
+    }
+
+    #[test]
+    fn test_diff_folders() {
+        let base = temp_assets(&[
+            ("fireball.spell.ron", ""),
+            ("heal.spell.ron", ""),
+            ("fire/meteor.spell.ron", ""),
+            ("_old.spell.ron", ""),
+            ("notes.txt", ""),
+        ]);
+        let sub_mod = temp_assets(&[
+            ("fireball.spell.ron", ""),
+            ("extra/blink.spell.ron", ""),
+            ("meteor.item.ron", ""),
+        ]);
+
+        let diff = diff_folders(&base, &sub_mod, ".spell.ron").unwrap();
+        assert_eq!(diff.only_in_first, vec!["heal", "meteor"]);
+        assert_eq!(diff.only_in_second, vec!["blink"]);
+        assert_eq!(diff.in_both, vec!["fireball"]);
+
+        assert!(diff_folders(&base, &base.join("missing"), ".spell.ron").is_err());
+    }
+
+    #[test]
+    fn test_unload_folder_releases_assets() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/heal.spell.ron", "(name: \"Heal\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new(
+            "spells",
+            ".spell.ron",
+        ));
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        assert_eq!(app.world().resource::<Assets<TestSpell>>().len(), 2);
+
+        app.world_mut()
+            .write_message(UnloadFolder::<TestSpell>::new());
+        update_until(&mut app, |app| {
+            app.world().resource::<Assets<TestSpell>>().is_empty()
+        });
+        let world = app.world();
+        assert!(
+            world
+                .resource::<AssetFolder<TestId, TestSpell>>()
+                .is_empty()
+        );
+        let folder_handle = world.resource::<AssetFolderHandle<TestSpell>>();
+        assert!(folder_handle.is_unloaded());
+        assert!(folder_handle.handle.is_none());
+        assert_eq!(folder_handle.phase(), Phase::Idle);
+
+        // Stays unloaded until a reload is requested
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(app.world().resource::<Assets<TestSpell>>().is_empty());
+        app.world_mut()
+            .resource_mut::<AssetFolderHandle<TestSpell>>()
+            .request_reload();
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        assert_eq!(
+            app.world()
+                .resource::<AssetFolder<TestId, TestSpell>>()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_matched_extension() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/heal.spell.ron", "(name: \"Heal\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new(
+            "spells",
+            ".spell.ron",
+        ));
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        assert_eq!(
+            library.matched_extension(TestId("fireball")),
+            Some(".spell.ron")
+        );
+        assert_eq!(
+            library.matched_extension(TestId("heal")),
+            Some(".spell.ron")
+        );
+        assert_eq!(library.matched_extension(TestId("missing")), None);
+    }
+
+    #[test]
+    fn test_concurrency_hint_limits_loads_in_flight() {
+        let root = temp_assets(&[
+            ("spells/a.spell.ron", "(name: \"A\")"),
+            ("spells/b.spell.ron", "(name: \"B\")"),
+            ("spells/c.spell.ron", "(name: \"C\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                .two_pass(true)
+                .concurrency_hint(1),
+        );
+        assert_eq!(
+            app.world()
+                .resource::<SharedConfig<TestId, TestSpell>>()
+                .concurrency_hint,
+            Some(1)
+        );
+
+        let paths = [
+            "spells/a.spell.ron",
+            "spells/b.spell.ron",
+            "spells/c.spell.ron",
+        ];
+        update_until(&mut app, |app| {
+            let asset_server = app.world().resource::<AssetServer>();
+            let in_flight = paths
+                .iter()
+                .filter_map(|path| asset_server.get_handle::<TestSpell>(*path))
+                .filter(|handle| !asset_server.is_loaded(handle))
+                .count();
+            assert!(in_flight <= 1, "{in_flight} loads in flight");
+            is_loaded::<TestSpell>(app)
+        });
+        assert_eq!(
+            app.world()
+                .resource::<AssetFolder<TestId, TestSpell>>()
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_freeze_allows_reads() {
+        #[derive(Asset, Clone, Reflect)]
+        struct MockAsset;
+
+        let mut library: AssetFolder<&'static str, MockAsset> = AssetFolder::new();
+        library.insert("fireball", Handle::default());
+        assert_eq!(library.remove("fireball"), Some(Handle::default()));
+        library.insert("fireball", Handle::default());
+        library.freeze();
+
+        assert!(library.is_frozen());
+        assert!(library.contains("fireball"));
+        assert!(library.get("fireball").is_some());
+    }
+
+    #[test]
+    fn test_remove_and_clear() {
+        #[derive(Asset, Clone, Reflect)]
+        struct MockAsset;
+
+        let mut library: AssetFolder<&'static str, MockAsset> = AssetFolder::new();
+        library.register(
+            "fireball",
+            None,
+            Path::new("spells/fireball.spell.ron"),
+            Handle::default(),
+        );
+        library.insert("heal", Handle::default());
+        library.insert("blink", Handle::default());
+        assert_eq!(library.len(), 3);
+
+        assert!(library.remove("fireball").is_some());
+        assert!(library.remove("fireball").is_none());
+        assert_eq!(library.len(), 2);
+        assert!(!library.contains("fireball"));
+        assert_eq!(library.path_of("fireball"), None);
+        assert!(library.contains("heal"));
+
+        library.clear();
+        assert_eq!(library.len(), 0);
+        assert!(library.is_empty());
+        assert!(!library.contains("heal"));
+    }
+
+    #[test]
+    #[should_panic(expected = "insert called on a frozen library")]
+    fn test_freeze_guards_mutation() {
+        #[derive(Asset, Clone, Reflect)]
+        struct MockAsset;
+
+        let mut library: AssetFolder<&'static str, MockAsset> = AssetFolder::new();
+        library.freeze();
+        library.insert("fireball", Handle::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "iter_mut called on a frozen library")]
+    fn test_freeze_guards_map_access() {
+        #[derive(Asset, Clone, Reflect)]
+        struct MockAsset;
+
+        let mut library: AssetFolder<&'static str, MockAsset> = AssetFolder::new();
+        library.insert("fireball", Handle::default());
+        library.freeze();
+        let _ = library.iter_mut();
+    }
+
+    #[test]
+    fn test_id_subtype_delimiter() {
+        let root = temp_assets(&[
+            (
+                "spells/fireball@offensive.spell.ron",
+                "(name: \"Fireball\")",
+            ),
+            ("spells/heal@support.spell.ron", "(name: \"Heal\")"),
+            ("spells/blink.spell.ron", "(name: \"Blink\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                .with_id_subtype_delimiter('@'),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        assert_eq!(library.len(), 3);
+        assert_eq!(library.subtype(TestId("fireball")), Some("offensive"));
+        assert_eq!(library.subtype(TestId("heal")), Some("support"));
+        assert!(library.contains(TestId("blink")));
+        assert_eq!(library.subtype(TestId("blink")), None);
+    }
+
+    #[test]
+    #[cfg(feature = "test-util")]
+    fn test_populate_dummy() {
+        #[derive(Asset, Clone, Reflect)]
+        struct MockAsset;
+
+        let mut library: AssetFolder<String, MockAsset> = AssetFolder::with_capacity(100);
+        library.populate_dummy(100);
+        assert_eq!(library.len(), 100);
+        assert!(library.contains("dummy_0".to_string()));
+        assert!(library.contains("dummy_99".to_string()));
+        assert!(!library.contains("dummy_100".to_string()));
+    }
+
+    #[test]
+    fn test_compact_shrinks_capacity() {
+        #[derive(Asset, Clone, Reflect)]
+        struct MockAsset;
+
+        let mut library: AssetFolder<usize, MockAsset> = AssetFolder::new();
+        for id in 0..1000 {
+            library.insert(id, Handle::default());
+        }
+        for id in 10..1000 {
+            library.remove(id);
+        }
+        let capacity = library.assets.capacity();
+
+        library.compact();
+        assert!(library.assets.capacity() < capacity);
+        assert_eq!(library.len(), 10);
+        assert!(library.contains(9));
+    }
+
+    #[test]
+    fn test_recursive_ids_include_subfolders() {
+        let root = temp_assets(&[
+            ("spells/blink.spell.ron", "(name: \"Blink\")"),
+            ("spells/fire/fireball.spell.ron", "(name: \"Fire Ball\")"),
+            ("spells/ice/fireball.spell.ron", "(name: \"Ice Ball\")"),
+            ("spells/ice/deep/shard.spell.ron", "(name: \"Shard\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new_recursive(
+            "spells",
+            ".spell.ron",
+            Some(1),
+        ));
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let world = app.world();
+        let library = world.resource::<AssetFolder<TestId, TestSpell>>();
+        let assets = world.resource::<Assets<TestSpell>>();
+        let mut ids: Vec<_> = library.keys().map(|id| id.0).collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec!["blink", "fire/fireball", "ice/fireball"]);
+        let name = |id| &assets.get(library.get(TestId(id)).unwrap()).unwrap().name;
+        assert_eq!(name("fire/fireball"), "Fire Ball");
+        assert_eq!(name("ice/fireball"), "Ice Ball");
+
+        let report = world.resource::<AssetFolderHandle<TestSpell>>();
+        assert!(report.all_entries(library).contains(&EntryStatus::Skipped(
+            "spells/ice/deep/shard.spell.ron".to_string(),
+            SkipReason::TooDeep
+        )));
+    }
+
+    #[test]
+    fn test_id_base_strip_drops_leading_subfolders() {
+        let root = temp_assets(&[
+            ("spells/blink.spell.ron", "(name: \"Blink\")"),
+            ("spells/fire/nova.spell.ron", "(name: \"Nova\")"),
+            ("spells/fire/arcane/bolt.spell.ron", "(name: \"Bolt\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new_recursive("spells", ".spell.ron", None)
+                .id_base_strip(1),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        let mut ids: Vec<_> = library.keys().map(|id| id.0).collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec!["arcane/bolt", "blink", "nova"]);
+    }
+
+    #[test]
+    fn test_library_from_loaded_folder() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/_draft.spell.ron", "(name: \"Draft\")"),
+            ("spells/potion.item.ron", "(name: \"Potion\")"),
+            ("spells/ice/fireball.spell.ron", "(name: \"Ice Ball\")"),
+        ]);
+        let mut app = test_app(&root);
+        let handle = app.world().resource::<AssetServer>().load_folder("spells");
+        update_until(&mut app, |app| {
+            app.world()
+                .resource::<Assets<LoadedFolder>>()
+                .contains(&handle)
+        });
+        let folders = app.world().resource::<Assets<LoadedFolder>>();
+        let folder = folders.get(&handle).unwrap();
+
+        let Err(FolderLoadError::DuplicateId { id, .. }) =
+            Library::<TestId, TestSpell>::try_from_loaded_folder(folder, ".spell.ron")
+        else {
+            panic!("expected a duplicate ID");
+        };
+        assert_eq!(id, "TestId(\"fireball\")");
+
+        let items =
+            Library::<TestId, TestItem>::try_from_loaded_folder(folder, ".item.ron").unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(
+            items.path_of(TestId("potion")),
+            Some(Path::new("spells/potion.item.ron"))
+        );
+    }
+
+    #[test]
+    fn test_path_of_survives_reload() {
+        let root = temp_assets(&[("spells/fire/fireball.spell.ron", "(name: \"Fireball\")")]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new(
+            "spells",
+            ".spell.ron",
+        ));
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        let path = Path::new("spells/fire/fireball.spell.ron");
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        assert_eq!(library.path_of(TestId("fireball")), Some(path));
+        assert_eq!(library.path_of(TestId("missing")), None);
+
+        app.world_mut()
+            .resource_mut::<AssetFolderHandle<TestSpell>>()
+            .request_reload();
+        app.update();
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        assert_eq!(library.path_of(TestId("fireball")), Some(path));
+    }
+
+    #[test]
+    fn test_failure_reason_includes_loader_error() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/broken.spell.ron", "(damage: 10)"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron").two_pass(true),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let errors = app
+            .world()
+            .resource::<AssetFolderHandle<TestSpell>>()
+            .errors();
+        let [FolderLoadError::AssetFailed { path, reason }] = errors.as_slice() else {
+            panic!("expected one asset failure, got {errors:?}");
+        };
+        assert_eq!(path, "spells/broken.spell.ron");
+        assert!(
+            reason.contains("Unexpected missing field named `name`"),
+            "{reason}"
+        );
+    }
+
+    #[test]
+    fn test_case_insensitive_extension() {
+        let root = temp_assets(&[
+            ("spells/frost.SPELL.RON", "(name: \"Frost\")"),
+            ("spells/heal.Spell.ron", "(name: \"Heal\")"),
+            ("spells/FireBall.spell.ron", "(name: \"Fireball\")"),
+            ("spells/_old.Spell.Ron", "(name: \"Old\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                .two_pass(true)
+                .case_insensitive(),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        let mut ids: Vec<_> = library.keys().map(|id| id.0).collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec!["FireBall", "frost", "heal"]);
+        let report = app.world().resource::<AssetFolderHandle<TestSpell>>();
+        assert!(report.all_entries(library).contains(&EntryStatus::Skipped(
+            "spells/_old.Spell.Ron".to_string(),
+            SkipReason::Disabled
+        )));
+    }
+
+    #[test]
+    fn test_extension_match_is_case_sensitive_by_default() {
+        let root = temp_assets(&[
+            ("spells/frost.SPELL.RON", "(name: \"Frost\")"),
+            ("spells/heal.spell.ron", "(name: \"Heal\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron").two_pass(true),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        assert_eq!(library.len(), 1);
+        assert!(library.contains(TestId("heal")));
+    }
+
+    #[test]
+    fn test_errors_distinguish_problem_kinds() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/fire/fireball.spell.ron", "(name: \"Fireball 2\")"),
+            ("spells/broken.spell.ron", "(damage: 10)"),
+            ("spells/bad id.spell.ron", "(name: \"Bad\")"),
+            ("spells/notes.txt", ""),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                .two_pass(true)
+                .validate_id_charset(|id| !id.contains(' ')),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let errors = app
+            .world()
+            .resource::<AssetFolderHandle<TestSpell>>()
+            .errors();
+        assert_eq!(errors.len(), 3, "{errors:?}");
+        assert!(matches!(
+            &errors[0],
+            FolderLoadError::AssetFailed { path, reason }
+                if path == "spells/broken.spell.ron" && reason.contains("missing field")
+        ));
+        assert_eq!(
+            errors[1],
+            FolderLoadError::InvalidIdChars {
+                path: "spells/bad id.spell.ron".to_string()
+            }
+        );
+        assert!(matches!(
+            &errors[2],
+            FolderLoadError::DuplicateId { id, .. } if id == "TestId(\"fireball\")"
+        ));
+    }
+
+    #[test]
+    fn test_duplicate_policy_keep_first() {
+        let root = temp_assets(&[("spells/fireball.spell.ron", "(name: \"Fireball\")")]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                .on_duplicate(DuplicatePolicy::KeepFirst),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        // A file added later under an existing ID collides with the first one
+        std::fs::create_dir_all(root.join("spells/fire")).unwrap();
+        std::fs::write(
+            root.join("spells/fire/fireball.spell.ron"),
+            "(name: \"Imposter\")",
+        )
+        .unwrap();
+        let _added: Handle<TestSpell> = app
+            .world()
+            .resource::<AssetServer>()
+            .load("spells/fire/fireball.spell.ron");
+        update_until(&mut app, |app| {
+            !app.world()
+                .resource::<AssetFolderHandle<TestSpell>>()
+                .report()
+                .duplicates
+                .is_empty()
+        });
+
+        let world = app.world();
+        let library = world.resource::<AssetFolder<TestId, TestSpell>>();
+        assert_eq!(
+            library.path_of(TestId("fireball")),
+            Some(Path::new("spells/fireball.spell.ron"))
+        );
+        assert_eq!(
+            world.resource::<AssetFolderHandle<TestSpell>>().errors(),
+            vec![FolderLoadError::DuplicateId {
+                id: "TestId(\"fireball\")".to_string(),
+                first_path: "spells/fireball.spell.ron".to_string(),
+                second_path: "spells/fire/fireball.spell.ron".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_duplicate_policy_keep_last_replaces_hot_added() {
+        let root = temp_assets(&[("spells/fireball.spell.ron", "(name: \"Fireball\")")]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                .on_duplicate(DuplicatePolicy::KeepLast),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        std::fs::create_dir_all(root.join("spells/fire")).unwrap();
+        std::fs::write(
+            root.join("spells/fire/fireball.spell.ron"),
+            "(name: \"Override\")",
+        )
+        .unwrap();
+        let _added: Handle<TestSpell> = app
+            .world()
+            .resource::<AssetServer>()
+            .load("spells/fire/fireball.spell.ron");
+        update_until(&mut app, |app| {
+            app.world()
+                .resource::<AssetFolder<TestId, TestSpell>>()
+                .path_of(TestId("fireball"))
+                == Some(Path::new("spells/fire/fireball.spell.ron"))
+        });
+
+        let world = app.world();
+        let library = world.resource::<AssetFolder<TestId, TestSpell>>();
+        let assets = world.resource::<Assets<TestSpell>>();
+        assert_eq!(library.len(), 1);
+        assert_eq!(
+            assets
+                .get(library.get(TestId("fireball")).unwrap())
+                .unwrap()
+                .name,
+            "Override"
+        );
+        let report = world.resource::<AssetFolderHandle<TestSpell>>().report();
+        assert_eq!(report.loaded.len(), 1);
+        assert_eq!(report.duplicates.len(), 1);
+    }
+
+    #[test]
+    fn test_request_reload_rescans_folder() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/heal.spell.ron", "(name: \"Heal\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new(
+            "spells",
+            ".spell.ron",
+        ));
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        let first = app
+            .world()
+            .resource::<AssetFolderHandle<TestSpell>>()
+            .handle
+            .as_ref()
+            .unwrap()
+            .id();
+
+        std::fs::remove_file(root.join("spells/heal.spell.ron")).unwrap();
+        std::fs::write(root.join("spells/frost.spell.ron"), "(name: \"Frost\")").unwrap();
+        app.world_mut()
+            .resource_mut::<AssetFolderHandle<TestSpell>>()
+            .request_reload();
+        app.update();
+        let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+        assert!(folder_handle.handle.is_none());
+        assert_eq!(folder_handle.report(), LoadReport::default());
+        assert!(
+            app.world()
+                .resource::<AssetFolder<TestId, TestSpell>>()
+                .is_empty()
+        );
+
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        let world = app.world();
+        let library = world.resource::<AssetFolder<TestId, TestSpell>>();
+        let mut ids: Vec<_> = library.keys().map(|id| id.0).collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec!["fireball", "frost"]);
+        assert!(
+            world
+                .resource::<Assets<LoadedFolder>>()
+                .get(first)
+                .is_none()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "state")]
+    fn test_run_in_state_gates_loading_and_transitions() {
+        #[derive(States, Debug, Clone, PartialEq, Eq, Hash, Default)]
+        enum GameState {
+            #[default]
+            Menu,
+            AssetLoading,
+            InGame,
+        }
+
+        let root = temp_assets(&[("spells/fireball.spell.ron", "(name: \"Fireball\")")]);
+        let mut app = test_app(&root);
+        app.add_plugins(bevy::state::app::StatesPlugin)
+            .init_state::<GameState>()
+            .add_plugins(
+                FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                    .run_in_state(GameState::AssetLoading, Some(GameState::InGame)),
+            );
+
+        for _ in 0..5 {
+            app.update();
+        }
+        let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+        assert_eq!(folder_handle.phase(), Phase::Idle);
+
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::AssetLoading);
+        update_until(&mut app, |app| {
+            *app.world().resource::<State<GameState>>().get() == GameState::InGame
+        });
+        assert!(is_loaded::<TestSpell>(&app));
+        assert!(
+            app.world()
+                .resource::<AssetFolder<TestId, TestSpell>>()
+                .contains(TestId("fireball"))
+        );
+    }
+
+    #[test]
+    fn test_folder_load_progress_fraction() {
+        let mut progress = FolderLoadProgress::default();
+        assert_eq!(progress.fraction(), 1.0);
+
+        progress.register::<TestSpell>();
+        progress.register::<TestItem>();
+        progress.set::<TestSpell>(4, 1, false);
+        assert_eq!(progress.fraction(), 0.125);
+        progress.set::<TestItem>(0, 0, true);
+        assert_eq!(progress.fraction(), 0.625);
+        assert_eq!(
+            progress.per_type().collect::<Vec<_>>(),
+            vec![
+                (type_name::<TestSpell>(), 0.25),
+                (type_name::<TestItem>(), 1.0)
+            ]
+        );
     }
 
     #[test]
-    fn test_id_from_filename_hidden() {
-        let path = Path::new(".hidden.mock.ron");
-        let id: Option<MockId> = id_from_filename_with_extension(path, ".mock.ron");
-        assert!(id.is_none());
+    fn test_folder_load_progress_tracks_plugins() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("items/potion.item.ron", "(name: \"Potion\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins((
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron"),
+            FolderLoaderPlugin::<TestId, TestItem>::new("items", ".item.ron"),
+        ));
+        assert_eq!(app.world().resource::<FolderLoadProgress>().fraction(), 0.0);
+
+        update_until(&mut app, |app| {
+            is_loaded::<TestSpell>(app) && is_loaded::<TestItem>(app)
+        });
+        app.update();
+        let progress = app.world().resource::<FolderLoadProgress>();
+        assert_eq!(progress.fraction(), 1.0);
+        assert_eq!(progress.per_type().count(), 2);
     }
 
     #[test]
-    fn test_id_from_filename_disabled() {
-        let path = Path::new("_disabled.mock.ron");
-        let id: Option<MockId> = id_from_filename_with_extension(path, ".mock.ron");
-        assert!(id.is_none());
+    fn test_custom_id_parser() {
+        let root = temp_assets(&[
+            ("spells/01_fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/02_heal.spell.ron", "(name: \"Heal\")"),
+            ("spells/blink.spell.ron", "(name: \"Blink\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron").with_id_parser(
+                Box::new(|path| {
+                    let name = path.file_name()?.to_str()?.strip_suffix(".spell.ron")?;
+                    let (order, id) = name.split_once('_')?;
+                    order
+                        .chars()
+                        .all(|c| c.is_ascii_digit())
+                        .then(|| TestId::from(id.to_string()))
+                }),
+            ),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        assert_eq!(library.len(), 2);
+        assert!(library.contains(TestId("fireball")));
+        assert!(library.contains(TestId("heal")));
+        let report = app
+            .world()
+            .resource::<AssetFolderHandle<TestSpell>>()
+            .report();
+        assert_eq!(report.skipped, vec!["spells/blink.spell.ron".to_string()]);
     }
 
     #[test]
-    fn test_id_from_filename_wrong_extension() {
-        let path = Path::new("test_item.other.ron");
-        let id: Option<MockId> = id_from_filename_with_extension(path, ".mock.ron");
-        assert!(id.is_none());
+    fn test_with_extensions_loads_every_extension() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/heal.spell.txt", "(name: \"Heal\")"),
+            ("spells/blink.spell.bak", "(name: \"Blink\")"),
+        ]);
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(AssetPlugin {
+                file_path: root.to_string_lossy().into_owned(),
+                ..default()
+            })
+            .add_plugins(RonAssetPlugin::<TestSpell>::new(&[
+                "spell.ron",
+                "spell.txt",
+                "spell.bak",
+            ]))
+            .add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::with_extensions(
+                "spells",
+                &[".spell.ron", ".spell.txt"],
+            ));
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        assert_eq!(library.len(), 2);
+        assert_eq!(
+            library.matched_extension(TestId("fireball")),
+            Some(".spell.ron")
+        );
+        assert_eq!(
+            library.matched_extension(TestId("heal")),
+            Some(".spell.txt")
+        );
+        assert!(!library.contains(TestId("blink")));
     }
 
     #[test]
-    fn test_is_hidden_file() {
-        assert!(is_hidden_file(Path::new(".hidden.ron")));
-        assert!(is_hidden_file(Path::new("_disabled.ron")));
-        assert!(!is_hidden_file(Path::new("normal.ron")));
+    fn test_iter_sorted_follows_source_paths() {
+        let root = temp_assets(&[
+            ("spells/ice_shard.spell.ron", "(name: \"Ice Shard\")"),
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/heal.spell.ron", "(name: \"Heal\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new(
+            "spells",
+            ".spell.ron",
+        ));
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+
+        let mut library = app
+            .world_mut()
+            .resource_mut::<AssetFolder<TestId, TestSpell>>();
+        library.insert(TestId("manual"), Handle::default());
+        let ids: Vec<_> = library.iter_sorted().map(|(id, _)| id.0).collect();
+        assert_eq!(ids, vec!["fireball", "heal", "ice_shard", "manual"]);
     }
 
     #[test]
-    fn test_asset_folder_handle_states() {
-        // Mock asset type for testing
-        #[derive(Asset, Clone, Reflect, Default)]
-        struct MockAsset;
+    fn test_hot_reload_syncs_reloaded_folder() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/heal.spell.ron", "(name: \"Heal\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron").hot_reload(true),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
 
-        let mut handle: AssetFolderHandle<MockAsset> = AssetFolderHandle::new();
+        // Simulate the file watcher reloading the folder without `heal`
+        let folder = app
+            .world()
+            .resource::<AssetFolderHandle<TestSpell>>()
+            .handle
+            .clone()
+            .unwrap();
+        app.world_mut()
+            .resource_mut::<Assets<LoadedFolder>>()
+            .get_mut(&folder)
+            .unwrap()
+            .handles
+            .retain(|handle| handle.path().unwrap().path() != Path::new("spells/heal.spell.ron"));
+        // The asset event is sent at the end of the frame
+        app.update();
+        app.update();
 
-        // Initial state
-        assert!(!handle.is_loaded());
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        assert!(library.contains(TestId("fireball")));
+        assert!(!library.contains(TestId("heal")));
+        assert_eq!(library.path_of(TestId("heal")), None);
+        assert_eq!(
+            app.world()
+                .resource::<AssetFolderHandle<TestSpell>>()
+                .total(),
+            1
+        );
+    }
 
-        // After starting load
-        handle.handle = Some(Handle::default());
-        assert!(!handle.is_loaded());
+    #[test]
+    fn test_validator_rejects_invalid_assets() {
+        let root = temp_assets(&[
+            (
+                "spells/fireball.spell.ron",
+                "(name: \"Fireball\", damage: 50.0)",
+            ),
+            (
+                "spells/cursed.spell.ron",
+                "(name: \"Cursed\", damage: -5.0)",
+            ),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron").with_validator(
+                Box::new(|spell| {
+                    if spell.damage < 0.0 {
+                        Err(format!("negative damage {}", spell.damage))
+                    } else {
+                        Ok(())
+                    }
+                }),
+            ),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
 
-        // After processing complete
-        handle.processed = true;
-        assert!(handle.is_loaded());
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        assert!(library.contains(TestId("fireball")));
+        assert!(!library.contains(TestId("cursed")));
+        let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+        assert_eq!(
+            folder_handle.errors(),
+            vec![FolderLoadError::ValidationFailed {
+                path: "spells/cursed.spell.ron".to_string(),
+                reason: "negative damage -5".to_string(),
+            }]
+        );
+        assert_eq!(folder_handle.report().failed.len(), 1);
     }
 
     #[test]
-    fn test_folder_asset_library() {
-        #[derive(Asset, Clone, Reflect, Default)]
-        struct MockAsset;
-
-        let mut library: AssetFolder<MockId, MockAsset> = AssetFolder::new();
+    fn test_loaded_and_pending_counts() {
+        let root = temp_assets(&[
+            ("spells/a.spell.ron", "(name: \"A\")"),
+            ("spells/b.spell.ron", "(name: \"B\")"),
+            ("spells/c.spell.ron", "(name: \"C\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new(
+            "spells",
+            ".spell.ron",
+        ));
+        let mut last_loaded = 0;
+        update_until(&mut app, |app| {
+            let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+            assert!(folder_handle.loaded() >= last_loaded);
+            assert!(folder_handle.loaded() + folder_handle.pending() <= 3);
+            last_loaded = folder_handle.loaded();
+            folder_handle.is_loaded()
+        });
+        let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+        assert_eq!(folder_handle.loaded(), 3);
+        assert_eq!(folder_handle.pending(), 0);
 
-        assert!(library.is_empty());
-        assert_eq!(library.len(), 0);
-        assert!(!library.is_ready());
+        app.world_mut()
+            .resource_mut::<AssetFolderHandle<TestSpell>>()
+            .request_reload();
+        app.update();
+        let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+        assert_eq!(folder_handle.loaded(), 0);
+        assert_eq!(folder_handle.pending(), 0);
+    }
 
-        let id = MockId(1);
-        library.insert(id, Handle::default());
+    #[test]
+    fn test_release_folder_handle_after_load() {
+        let root = temp_assets(&[("spells/fireball.spell.ron", "(name: \"Fireball\")")]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                .retain_folder_handle(false),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        for _ in 0..3 {
+            app.update();
+        }
 
-        assert!(!library.is_empty());
-        assert_eq!(library.len(), 1);
-        assert!(library.is_ready());
-        assert!(library.contains(id));
-        assert!(library.get(id).is_some());
+        assert!(
+            app.world()
+                .resource::<AssetFolderHandle<TestSpell>>()
+                .handle
+                .is_none()
+        );
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        let assets = app.world().resource::<Assets<TestSpell>>();
+        assert_eq!(
+            library
+                .get_data(TestId("fireball"), assets)
+                .map(|spell| spell.name.as_str()),
+            Some("Fireball")
+        );
+    }
 
-        let keys: Vec<_> = library.keys().collect();
-        assert_eq!(keys.len(), 1);
+    #[test]
+    fn test_load_folder_blocking() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/heal.spell.ron", "(name: \"Heal\")"),
+            ("spells/_disabled.spell.ron", "(name: \"Disabled\")"),
+        ]);
+        let mut app = test_app(&root);
+        let library: AssetFolder<TestId, TestSpell> =
+            load_folder_blocking(&mut app, "spells", ".spell.ron", Duration::from_secs(5)).unwrap();
+        assert_eq!(library.len(), 2);
+        let assets = app.world().resource::<Assets<TestSpell>>();
+        assert_eq!(
+            library
+                .get_data(TestId("heal"), assets)
+                .map(|spell| spell.name.as_str()),
+            Some("Heal")
+        );
 
-        let iter_count = library.iter().count();
-        assert_eq!(iter_count, 1);
+        let root = temp_assets(&[("spells/broken.spell.ron", "(name: ")]);
+        let mut app = test_app(&root);
+        let result: Result<AssetFolder<TestId, TestSpell>, _> =
+            load_folder_blocking(&mut app, "spells", ".spell.ron", Duration::from_secs(5));
+        assert!(matches!(
+            result,
+            Err(FolderLoadError::AssetFailed { path, .. }) if path == "spells/broken.spell.ron"
+        ));
     }
 
     #[test]
-    fn test_atlas_icon() {
-        let icon = AtlasIcon::new(Handle::default(), Handle::default(), 5);
-
-        assert_eq!(icon.atlas_index, 5);
+    fn test_include_and_exclude_patterns() {
+        let root = temp_assets(&[
+            ("actors/boss_dragon.spell.ron", "(name: \"Dragon\")"),
+            ("actors/boss_lich_wip.spell.ron", "(name: \"Lich\")"),
+            ("actors/minion_goblin.spell.ron", "(name: \"Goblin\")"),
+            ("actors/elite_knight.spell.ron", "(name: \"Knight\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("actors", ".spell.ron")
+                .with_include_pattern("boss_*")
+                .with_include_pattern("elite_*")
+                .with_exclude_pattern("*_wip.*"),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
 
-        let atlas = icon.texture_atlas();
-        assert_eq!(atlas.index, 5);
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        let mut ids: Vec<_> = library.keys().map(|id| id.0).collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec!["boss_dragon", "elite_knight"]);
+        let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+        assert!(folder_handle.errors().is_empty());
+        assert_eq!(folder_handle.report().skipped.len(), 2);
     }
 
-    // ==========================================================================
-    // Additional tests for Bevy 0.17 migration validation
-    // ==========================================================================
-
     #[test]
-    fn test_id_from_filename_extracts_correct_id() {
-        let path = Path::new("fireball.spell.ron");
-        let id: Option<MockId> = id_from_filename_with_extension(path, ".spell.ron");
-        assert!(id.is_some());
-        // "fireball" has 8 characters
-        assert_eq!(id.unwrap(), MockId(8));
+    fn test_deserialize_optional_f32() {
+        #[derive(Deserialize)]
+        struct Data {
+            #[serde(default, deserialize_with = "deserialize_optional_f32")]
+            cooldown: Option<f32>,
+        }
+
+        let data: Data = ron::from_str("(cooldown: 1.5)").unwrap();
+        assert_eq!(data.cooldown, Some(1.5));
+        let data: Data = ron::from_str("(cooldown: 0.0)").unwrap();
+        assert_eq!(data.cooldown, None);
+        let data: Data = ron::from_str("()").unwrap();
+        assert_eq!(data.cooldown, None);
     }
 
     #[test]
-    fn test_id_from_filename_with_nested_path() {
-        let path = Path::new("prefabs/spells/fireball.spell.ron");
-        let id: Option<MockId> = id_from_filename_with_extension(path, ".spell.ron");
-        assert!(id.is_some());
-        assert_eq!(id.unwrap(), MockId(8)); // "fireball"
+    fn test_deserialize_nonempty_vec() {
+        #[derive(Deserialize, Debug)]
+        struct Data {
+            #[serde(deserialize_with = "deserialize_nonempty_vec")]
+            tags: Vec<String>,
+        }
+
+        let data: Data = ron::from_str("(tags: [\"fire\", \"aoe\"])").unwrap();
+        assert_eq!(data.tags, vec!["fire", "aoe"]);
+        let err = ron::from_str::<Data>("(tags: [])").unwrap_err();
+        assert!(err.to_string().contains("expected a non-empty array"));
     }
 
     #[test]
-    fn test_id_from_filename_empty_id() {
-        // Extension only - should return None
-        let path = Path::new(".spell.ron");
-        let id: Option<MockId> = id_from_filename_with_extension(path, ".spell.ron");
-        assert!(id.is_none());
+    fn test_from_filename_without_from_string() {
+        #[derive(Debug, PartialEq)]
+        struct LenId(usize);
+
+        impl FromFilename for LenId {
+            fn from_filename(id: &str) -> Self {
+                LenId(id.len())
+            }
+        }
+
+        let id: Option<LenId> = id_from_filename(Path::new("fireball.spell.ron"), ".spell.ron");
+        assert_eq!(id, Some(LenId(8)));
+        assert_eq!(String::from_filename("heal"), "heal");
     }
 
     #[test]
-    fn test_legacy_id_from_filename() {
-        let path = Path::new("test_item.mock.ron");
-        let id: Option<MockId> = id_from_filename(path, ".mock.ron");
-        assert!(id.is_some());
-        assert_eq!(id.unwrap(), MockId(9)); // "test_item"
+    fn test_config_id_borrows_from_path() {
+        let path = Path::new("spells/fireball.spell.ron");
+        let config = FolderLoaderPlugin::<String, TestSpell>::new("spells", ".spell.ron").config();
+        assert!(matches!(
+            config.id_parts_for(path),
+            Some((Some(Cow::Borrowed("fireball")), None))
+        ));
     }
 
     #[test]
-    fn test_is_hidden_file_with_nested_paths() {
-        assert!(is_hidden_file(Path::new("some/path/.hidden.ron")));
-        assert!(is_hidden_file(Path::new("some/path/_disabled.ron")));
-        assert!(!is_hidden_file(Path::new("some/path/normal.ron")));
+    fn test_id_from_filename_with_prefixes() {
+        let prefixes = ['.', '~'];
+        let id: Option<String> =
+            id_from_filename_with_prefixes(Path::new("~draft.spell.ron"), ".spell.ron", &prefixes);
+        assert_eq!(id, None);
+        let id: Option<String> =
+            id_from_filename_with_prefixes(Path::new("_base.spell.ron"), ".spell.ron", &prefixes);
+        assert_eq!(id.as_deref(), Some("_base"));
     }
 
     #[test]
-    fn test_asset_folder_multiple_assets() {
-        #[derive(Asset, Clone, Reflect, Default)]
-        struct MockAsset;
-
-        let mut library: AssetFolder<MockId, MockAsset> = AssetFolder::new();
+    fn test_custom_skip_prefixes() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/~draft.spell.ron", "(name: \"Draft\")"),
+            ("spells/_base.spell.ron", "(name: \"Base\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                .skip_prefixes(&['.', '~'])
+                .on_skip(|path, reason| {
+                    assert_eq!(path, Path::new("spells/~draft.spell.ron"));
+                    assert_eq!(reason, SkipReason::Disabled);
+                }),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
 
-        // Insert multiple assets
-        for i in 0..10 {
-            library.insert(MockId(i), Handle::default());
-        }
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        assert_eq!(library.len(), 2);
+        assert!(library.contains(TestId("fireball")));
+        assert!(library.contains(TestId("_base")));
+        assert!(!library.contains(TestId("~draft")));
+    }
 
-        assert_eq!(library.len(), 10);
-        assert!(library.is_ready());
+    #[test]
+    fn test_missing_folder_is_reported() {
+        for two_pass in [false, true] {
+            let root = temp_assets(&[("spells/fireball.spell.ron", "(name: \"Fireball\")")]);
+            let mut app = test_app(&root);
+            app.add_plugins(
+                FolderLoaderPlugin::<TestId, TestSpell>::new("spels", ".spell.ron")
+                    .two_pass(two_pass),
+            );
+            update_until(&mut app, |app| is_loaded::<TestSpell>(app));
 
-        // Verify all are accessible
-        for i in 0..10 {
-            assert!(library.contains(MockId(i)));
-            assert!(library.get(MockId(i)).is_some());
+            assert_eq!(
+                app.world()
+                    .resource::<AssetFolderHandle<TestSpell>>()
+                    .errors(),
+                vec![FolderLoadError::FolderNotFound {
+                    path: "spels".to_string()
+                }]
+            );
         }
-
-        // Test keys count
-        let keys: Vec<_> = library.keys().collect();
-        assert_eq!(keys.len(), 10);
-
-        // Test iteration
-        let iter_count = library.iter().count();
-        assert_eq!(iter_count, 10);
     }
 
     #[test]
-    fn test_asset_folder_get_mut() {
-        #[derive(Asset, Clone, Reflect, Default)]
-        struct MockAsset;
+    fn test_library_knows_its_source() {
+        assert_eq!(AssetFolder::<TestId, TestSpell>::new().source_folder(), "");
 
-        let mut library: AssetFolder<MockId, MockAsset> = AssetFolder::new();
-        let id = MockId(1);
-        library.insert(id, Handle::default());
+        let root = temp_assets(&[("spells/fireball.spell.ron", "(name: \"Fireball\")")]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new(
+            "spells",
+            ".spell.ron",
+        ));
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
 
-        // Test mutable access
-        assert!(library.get_mut(id).is_some());
-        assert!(library.get_mut(MockId(999)).is_none());
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        assert_eq!(library.source_folder(), "spells");
+        assert_eq!(library.source_extension(), ".spell.ron");
     }
 
     #[test]
-    fn test_asset_folder_insert_returns_old_value() {
-        #[derive(Asset, Clone, Reflect, Default)]
-        struct MockAsset;
+    fn test_with_folders_merges_and_overrides() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/heal.spell.ron", "(name: \"Heal\")"),
+            (
+                "dlc/spells/fireball.spell.ron",
+                "(name: \"Patched Fireball\")",
+            ),
+            ("dlc/spells/meteor.spell.ron", "(name: \"Meteor\")"),
+        ]);
+        for (policy, fireball) in [
+            (DuplicatePolicy::KeepLast, "dlc/spells/fireball.spell.ron"),
+            (DuplicatePolicy::KeepFirst, "spells/fireball.spell.ron"),
+        ] {
+            let mut app = test_app(&root);
+            app.add_plugins(
+                FolderLoaderPlugin::<TestId, TestSpell>::with_folders(
+                    &["spells", "dlc/spells"],
+                    ".spell.ron",
+                )
+                .on_duplicate(policy),
+            );
+            update_until(&mut app, |app| is_loaded::<TestSpell>(app));
 
-        let mut library: AssetFolder<MockId, MockAsset> = AssetFolder::new();
-        let id = MockId(1);
+            let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+            assert_eq!(library.len(), 3);
+            assert!(library.contains(TestId("heal")));
+            assert!(library.contains(TestId("meteor")));
+            assert_eq!(
+                library.path_of(TestId("fireball")),
+                Some(Path::new(fireball))
+            );
+            let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+            assert_eq!(folder_handle.total(), 3);
+            assert_eq!(folder_handle.report().duplicates.len(), 1);
+        }
+    }
 
-        // First insert returns None
-        let old = library.insert(id, Handle::default());
-        assert!(old.is_none());
+    #[test]
+    fn test_duplicates_resolve_in_path_order() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("spells/fire/fireball.spell.ron", "(name: \"Imposter\")"),
+        ]);
+        for two_pass in [false, true] {
+            for (policy, kept) in [
+                (DuplicatePolicy::KeepFirst, "spells/fire/fireball.spell.ron"),
+                (DuplicatePolicy::KeepLast, "spells/fireball.spell.ron"),
+            ] {
+                let mut app = test_app(&root);
+                app.add_plugins(
+                    FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                        .two_pass(two_pass)
+                        .on_duplicate(policy),
+                );
+                update_until(&mut app, |app| is_loaded::<TestSpell>(app));
 
-        // Second insert returns the old handle
-        let old = library.insert(id, Handle::default());
-        assert!(old.is_some());
+                let world = app.world();
+                let library = world.resource::<AssetFolder<TestId, TestSpell>>();
+                assert_eq!(library.path_of(TestId("fireball")), Some(Path::new(kept)));
+                assert_eq!(
+                    world.resource::<AssetFolderHandle<TestSpell>>().errors(),
+                    vec![FolderLoadError::DuplicateId {
+                        id: "TestId(\"fireball\")".to_string(),
+                        first_path: "spells/fire/fireball.spell.ron".to_string(),
+                        second_path: "spells/fireball.spell.ron".to_string(),
+                    }]
+                );
+            }
+        }
     }
 
     #[test]
-    fn test_asset_folder_deref() {
-        #[derive(Asset, Clone, Reflect, Default)]
-        struct MockAsset;
+    fn test_overlapping_folders_are_not_duplicates() {
+        let root = temp_assets(&[
+            ("spells/heal.spell.ron", "(name: \"Heal\")"),
+            ("spells/fire/fireball.spell.ron", "(name: \"Fireball\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::with_folders(
+            &["spells", "spells/fire"],
+            ".spell.ron",
+        ));
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
 
-        let mut library: AssetFolder<MockId, MockAsset> = AssetFolder::new();
-        library.insert(MockId(1), Handle::default());
-
-        // Test Deref access to HashMap methods
-        assert!(library.contains_key(&MockId(1)));
-        assert!(!library.contains_key(&MockId(2)));
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        assert_eq!(library.len(), 2);
+        let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+        assert_eq!(folder_handle.total(), 2);
+        assert!(folder_handle.report().duplicates.is_empty());
     }
 
-
     #[test]
-    fn test_atlas_icon_image_node_creation() {
-        let icon = AtlasIcon::new(Handle::default(), Handle::default(), 3);
+    fn test_in_schedule_runs_loader_in_that_schedule() {
+        #[derive(bevy::ecs::schedule::ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+        struct LoadSchedule;
 
-        // Test that image_node() creates a valid ImageNode
-        let _image_node = icon.image_node();
+        let root = temp_assets(&[("spells/fireball.spell.ron", "(name: \"Fireball\")")]);
+        let mut app = test_app(&root);
+        app.init_schedule(LoadSchedule);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                .in_schedule(LoadSchedule),
+        );
+        for _ in 0..5 {
+            app.update();
+        }
+        assert_eq!(
+            app.world()
+                .resource::<AssetFolderHandle<TestSpell>>()
+                .phase(),
+            Phase::Idle
+        );
 
-        // Test get_image returns a handle
-        let _image = icon.get_image();
+        update_until(&mut app, |app| {
+            app.world_mut().run_schedule(LoadSchedule);
+            is_loaded::<TestSpell>(app)
+        });
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        assert!(library.contains(TestId("fireball")));
     }
 
     #[test]
-    fn test_atlas_icon_default() {
-        let icon = AtlasIcon::default();
+    fn test_get_or_default_returns_fallback() {
+        let root = temp_assets(&[
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+            ("missing.spell.ron", "(name: \"Missing\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron")
+                .with_fallback("missing.spell.ron"),
+        );
+        update_until(&mut app, |app| {
+            let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+            is_loaded::<TestSpell>(app)
+                && app
+                    .world()
+                    .resource::<Assets<TestSpell>>()
+                    .contains(library.fallback())
+        });
 
-        assert_eq!(icon.atlas_index, 0);
+        let library = app.world().resource::<AssetFolder<TestId, TestSpell>>();
+        let assets = app.world().resource::<Assets<TestSpell>>();
+        assert_eq!(library.len(), 1);
+        let name = |id| assets.get(library.get_or_default(id)).unwrap().name.clone();
+        assert_eq!(name(TestId("fireball")), "Fireball");
+        assert_eq!(name(TestId("frostbolt")), "Missing");
     }
 
     #[test]
-    fn test_atlas_icon_equality() {
-        let icon1 = AtlasIcon::new(Handle::default(), Handle::default(), 5);
-        let _icon2 = AtlasIcon::new(Handle::default(), Handle::default(), 5);
-        let icon3 = AtlasIcon::new(Handle::default(), Handle::default(), 3);
+    fn test_with_reflection_exposes_ids() {
+        #[derive(Reflect, Clone, PartialEq, Eq, Hash, Debug, Default)]
+        struct ReflectId(String);
 
-        // Note: Handle::default() creates different handles each time,
-        // so icon1 == icon2 may be false depending on implementation
-        // But icon should not equal one with different index
-        assert_ne!(icon1.atlas_index, icon3.atlas_index);
+        impl From<String> for ReflectId {
+            fn from(s: String) -> Self {
+                Self(s)
+            }
+        }
+
+        let root = temp_assets(&[
+            ("spells/heal.spell.ron", "(name: \"Heal\")"),
+            ("spells/fireball.spell.ron", "(name: \"Fireball\")"),
+        ]);
+        let mut app = test_app(&root);
+        app.add_plugins(
+            FolderLoaderPlugin::<ReflectId, TestSpell>::new("spells", ".spell.ron")
+                .with_reflection(),
+        );
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        app.update();
+
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+        let reflect_resource = registry
+            .get_type_data::<ReflectResource>(std::any::TypeId::of::<
+                AssetFolder<ReflectId, TestSpell>,
+            >())
+            .unwrap();
+        let library = reflect_resource.reflect(app.world()).unwrap();
+        let bevy::reflect::ReflectRef::Struct(library) = library.reflect_ref() else {
+            panic!("AssetFolder should reflect as a struct");
+        };
+        let ids = library
+            .field("ids")
+            .and_then(|ids| ids.try_downcast_ref::<Vec<ReflectId>>())
+            .unwrap();
+        assert_eq!(
+            *ids,
+            vec![ReflectId("fireball".into()), ReflectId("heal".into())]
+        );
+        assert!(registry.contains(std::any::TypeId::of::<AssetFolderHandle<TestSpell>>()));
     }
 
     #[test]
-    fn test_asset_folder_handle_default() {
-        #[derive(Asset, Clone, Reflect, Default)]
-        struct MockAsset;
-
-        let handle: AssetFolderHandle<MockAsset> = AssetFolderHandle::default();
+    fn test_load_duration_is_recorded() {
+        let root = temp_assets(&[("spells/fireball.spell.ron", "(name: \"Fireball\")")]);
+        let mut app = test_app(&root);
+        app.add_plugins(FolderLoaderPlugin::<TestId, TestSpell>::new(
+            "spells",
+            ".spell.ron",
+        ));
+        app.update();
+        let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+        assert_eq!(folder_handle.load_duration(), None);
 
-        assert!(!handle.is_loaded());
-        assert!(handle.handle.is_none());
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+        assert!(folder_handle.load_duration().unwrap() > Duration::ZERO);
     }
 
     #[test]
-    fn test_asset_folder_default() {
-        #[derive(Asset, Clone, Reflect, Default)]
-        struct MockAsset;
+    fn test_loads_without_time_resource() {
+        let root = temp_assets(&[("spells/fireball.spell.ron", "(name: \"Fireball\")")]);
+        let mut app = App::new();
+        app.add_plugins((
+            bevy::app::TaskPoolPlugin::default(),
+            AssetPlugin {
+                file_path: root.to_string_lossy().into_owned(),
+                ..default()
+            },
+            RonAssetPlugin::<TestSpell>::new(&["spell.ron"]),
+            FolderLoaderPlugin::<TestId, TestSpell>::new("spells", ".spell.ron"),
+        ));
+        assert!(!app.world().contains_resource::<Time>());
 
-        let library: AssetFolder<MockId, MockAsset> = AssetFolder::default();
-
-        assert!(library.is_empty());
-        assert!(!library.is_ready());
+        update_until(&mut app, |app| is_loaded::<TestSpell>(app));
+        let folder_handle = app.world().resource::<AssetFolderHandle<TestSpell>>();
+        assert_eq!(folder_handle.load_duration(), Some(Duration::ZERO));
+        assert!(
+            app.world()
+                .resource::<AssetFolder<TestId, TestSpell>>()
+                .contains(TestId("fireball"))
+        );
     }
 
     #[test]
-    fn test_asset_folder_assets_access() {
-        #[derive(Asset, Clone, Reflect, Default)]
-        struct MockAsset;
+    fn test_wait_for_dependencies_registers_after_nested_loads() {
+        #[derive(Asset, TypePath, Clone)]
+        struct TestActor {
+            #[dependency]
+            sprite: Handle<TestSpell>,
+        }
 
-        let mut library: AssetFolder<MockId, MockAsset> = AssetFolder::new();
-        library.insert(MockId(1), Handle::default());
+        #[derive(TypePath)]
+        struct TestActorLoader;
 
-        // Test direct HashMap access
-        let assets = library.assets();
-        assert_eq!(assets.len(), 1);
+        impl bevy::asset::AssetLoader for TestActorLoader {
+            type Asset = TestActor;
+            type Settings = ();
+            type Error = std::io::Error;
 
-        let assets_mut = library.assets_mut();
-        assets_mut.insert(MockId(2), Handle::default());
-        assert_eq!(library.len(), 2);
-    }
+            async fn load(
+                &self,
+                reader: &mut dyn bevy::asset::io::Reader,
+                _settings: &(),
+                load_context: &mut bevy::asset::LoadContext<'_>,
+            ) -> Result<TestActor, Self::Error> {
+                let mut bytes = Vec::new();
+                reader.read_to_end(&mut bytes).await?;
+                let sprite = String::from_utf8_lossy(&bytes).trim().to_string();
+                Ok(TestActor {
+                    sprite: load_context.load(sprite),
+                })
+            }
 
-    #[test]
-    fn test_asset_folder_iter_mut() {
-        #[derive(Asset, Clone, Reflect, Default)]
-        struct MockAsset;
+            fn extensions(&self) -> &[&str] {
+                &["actor"]
+            }
+        }
 
-        let mut library: AssetFolder<MockId, MockAsset> = AssetFolder::new();
-        library.insert(MockId(1), Handle::default());
-        library.insert(MockId(2), Handle::default());
+        /// Loads a `TestSpell` slowly, so it lags behind the actor.
+        #[derive(TypePath)]
+        struct SlowSpriteLoader;
 
-        // Test mutable iteration
-        let count = library.iter_mut().count();
-        assert_eq!(count, 2);
+        impl bevy::asset::AssetLoader for SlowSpriteLoader {
+            type Asset = TestSpell;
+            type Settings = ();
+            type Error = std::io::Error;
+
+            async fn load(
+                &self,
+                _reader: &mut dyn bevy::asset::io::Reader,
+                _settings: &(),
+                _load_context: &mut bevy::asset::LoadContext<'_>,
+            ) -> Result<TestSpell, Self::Error> {
+                std::thread::sleep(std::time::Duration::from_millis(50));
+                Ok(TestSpell {
+                    name: "Hero".to_string(),
+                    damage: 0.0,
+                })
+            }
+
+            fn extensions(&self) -> &[&str] {
+                &["sprite"]
+            }
+        }
+
+        #[derive(Resource, Default)]
+        struct Registered(Vec<bool>);
+
+        let root = temp_assets(&[
+            ("actors/hero.actor", "sprites/hero.sprite"),
+            ("sprites/hero.sprite", ""),
+        ]);
+        let mut app = test_app(&root);
+        app.init_asset::<TestActor>()
+            .register_asset_loader(TestActorLoader)
+            .register_asset_loader(SlowSpriteLoader)
+            .add_plugins(
+                FolderLoaderPlugin::<TestId, TestActor>::new("actors", ".actor")
+                    .wait_for_dependencies(true),
+            )
+            .init_resource::<Registered>()
+            .add_systems(
+                Update,
+                (|mut messages: MessageReader<AssetRegistered<TestId, TestActor>>,
+                  asset_server: Res<AssetServer>,
+                  mut registered: ResMut<Registered>| {
+                    registered.0.extend(
+                        messages.read().map(|message| {
+                            asset_server.is_loaded_with_dependencies(&message.handle)
+                        }),
+                    );
+                })
+                .after(load_assets_from_folder::<TestId, TestActor>),
+            );
+        update_until(&mut app, |app| is_loaded::<TestActor>(app));
+        app.update();
+
+        assert_eq!(app.world().resource::<Registered>().0, vec![true]);
+        let library = app.world().resource::<AssetFolder<TestId, TestActor>>();
+        let actor = app
+            .world()
+            .resource::<Assets<TestActor>>()
+            .get(library.get(TestId("hero")).unwrap())
+            .unwrap();
+        assert!(
+            app.world()
+                .resource::<Assets<TestSpell>>()
+                .contains(&actor.sprite)
+        );
     }
 }
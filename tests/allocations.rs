@@ -0,0 +1,78 @@
+//! Heap allocations made by ID extraction.
+//!
+//! Counting needs a global allocator, so these tests live in their own test
+//! binary instead of replacing the allocator for the unit tests.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::path::Path;
+
+use msg_load_folder::prelude::*;
+use msg_load_folder::{id_from_filename_with_extensions, id_from_filename_with_prefixes};
+
+/// Counts the heap allocations of the current thread.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+// SAFETY: defers to the system allocator, only counting the calls
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Number of heap allocations `f` makes on this thread.
+fn allocations<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    let value = f();
+    let count = ALLOCATIONS.with(Cell::get) - before;
+    drop(value);
+    count
+}
+
+/// An ID built from the length of the ID string, without allocating.
+#[derive(Clone, PartialEq, Eq, Hash, Default, Debug)]
+struct LenId(usize);
+
+impl FromFilename for LenId {
+    fn from_filename(id: &str) -> Self {
+        LenId(id.len())
+    }
+}
+
+#[test]
+fn test_id_extraction_allocates_only_the_id() {
+    let path = Path::new("spells/fireball.spell.ron");
+    assert_eq!(
+        allocations(|| id_from_filename::<String>(path, ".spell.ron")),
+        1
+    );
+    assert_eq!(
+        allocations(|| id_from_filename::<LenId>(path, ".spell.ron")),
+        0
+    );
+
+    let prefixes = ['.', '_'];
+    assert_eq!(
+        allocations(|| id_from_filename_with_prefixes::<String>(path, ".spell.ron", &prefixes)),
+        1
+    );
+    assert_eq!(
+        allocations(|| id_from_filename_with_extensions::<LenId>(
+            path,
+            &[".item.ron", ".spell.ron"]
+        )),
+        0
+    );
+}